@@ -0,0 +1,165 @@
+// Plain unbalanced binary search tree. Each node owns its children outright, so Box is all we need.
+// Inserting in sorted order turns it into a glorified linked list, which is why insert, iter and drop all use loops
+type Tree<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    left: Tree<T>,
+    right: Tree<T>,
+}
+
+pub struct BinarySearchTree<T: Ord> {
+    root: Tree<T>,
+    length: usize,
+}
+
+impl<T: Ord> BinarySearchTree<T> {
+    pub fn new() -> BinarySearchTree<T> {
+        BinarySearchTree {
+            root: None,
+            length: 0,
+        }
+    }
+
+    // Returns false (and drops the value) if it's already in the tree, duplicates aren't kept
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut current = &mut self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                std::cmp::Ordering::Less => &mut node.left,
+                std::cmp::Ordering::Greater => &mut node.right,
+                std::cmp::Ordering::Equal => return false,
+            };
+        }
+        *current = Some(Box::new(Node {
+            value,
+            left: None,
+            right: None,
+        }));
+        self.length += 1;
+        true
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                std::cmp::Ordering::Less => &node.left,
+                std::cmp::Ordering::Greater => &node.right,
+                std::cmp::Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    // In-order, so the values come out sorted
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left_edge(&self.root);
+        iter
+    }
+}
+
+impl<T: Ord> Default for BinarySearchTree<T> {
+    fn default() -> Self {
+        BinarySearchTree::new()
+    }
+}
+
+// Keeps the path of nodes we still have to come back to instead of recursing.
+// The top of the stack is always the smallest value not handed out yet
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left_edge(&mut self, mut current: &'a Tree<T>) {
+        while let Some(node) = current {
+            self.stack.push(node);
+            current = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.stack.pop()?;
+        self.push_left_edge(&node.right);
+        Some(&node.value)
+    }
+}
+
+// The default drop recurses down every branch, and a lopsided tree is as deep as it is long
+impl<T: Ord> Drop for BinarySearchTree<T> {
+    fn drop(&mut self) {
+        let mut pending: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = pending.pop() {
+            pending.extend(node.left.take());
+            pending.extend(node.right.take());
+        }
+    }
+}
+
+#[cfg(test)]
+mod bst_tests {
+    use super::*;
+
+    #[test]
+    fn test_in_order_is_sorted() {
+        let mut tree = BinarySearchTree::new();
+        // 37 and 101 share no factors, so this visits every number below 101 exactly once, nicely jumbled
+        for i in 0..101 {
+            assert!(tree.insert((i * 37) % 101));
+        }
+        assert_eq!(tree.len(), 101);
+        assert_eq!(
+            tree.iter().copied().collect::<Vec<_>>(),
+            (0..101).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut tree = BinarySearchTree::new();
+        for word in ["mango", "apple", "pear", "kiwi", "zucchini"] {
+            tree.insert(word);
+        }
+        assert!(tree.contains(&"kiwi"));
+        assert!(tree.contains(&"mango")); // the root
+        assert!(tree.contains(&"zucchini"));
+        assert!(!tree.contains(&"banana"));
+        assert!(!tree.contains(&"zzz"));
+    }
+
+    #[test]
+    fn test_duplicates_and_empty() {
+        let mut tree = BinarySearchTree::new();
+        assert!(tree.is_empty());
+        assert_eq!(tree.iter().next(), None);
+        assert!(!tree.contains(&1));
+        assert!(tree.insert(1));
+        assert!(!tree.insert(1));
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&1]);
+    }
+
+    #[test]
+    fn test_lopsided_tree() {
+        let mut tree = BinarySearchTree::new();
+        for i in 0..5_000 {
+            tree.insert(i); // every node goes to the right, so it's 5000 deep
+        }
+        assert_eq!(tree.iter().count(), 5_000);
+        drop(tree);
+    }
+}
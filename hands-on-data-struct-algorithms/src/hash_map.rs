@@ -0,0 +1,195 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::LinkedList;
+use std::hash::{Hash, Hasher};
+
+const INITIAL_BUCKETS: usize = 8;
+
+// Separate chaining: every bucket is a little list of the entries whose hash landed there, so a collision just
+// makes a chain one longer. std's LinkedList backs the chains because get has to hand out a plain &V, and the logs in
+// lists.rs can only hand out clones or RefCell guards.
+// Once there are more than 3 entries per 4 buckets the bucket count doubles and everything gets rehashed, which keeps
+// the chains short
+pub struct ChainedHashMap<K: Hash + Eq, V> {
+    buckets: Vec<LinkedList<(K, V)>>,
+    length: usize,
+}
+
+impl<K: Hash + Eq, V> ChainedHashMap<K, V> {
+    pub fn new() -> ChainedHashMap<K, V> {
+        ChainedHashMap {
+            buckets: Self::empty_buckets(INITIAL_BUCKETS),
+            length: 0,
+        }
+    }
+
+    // Hands back the old value if the key was already there, like HashMap::insert
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let index = self.bucket_index(&key);
+        if let Some((_, existing)) = self.buckets[index].iter_mut().find(|(k, _)| *k == key) {
+            return Some(std::mem::replace(existing, value));
+        }
+        self.buckets[index].push_back((key, value));
+        self.length += 1;
+        if self.length * 4 > self.buckets.len() * 3 {
+            self.grow();
+        }
+        None
+    }
+
+    // Borrow<Q> so a map keyed by String can be looked up with a &str
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.buckets[self.bucket_index(key)]
+            .iter()
+            .find(|(k, _)| k.borrow() == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    // LinkedList can't remove from the middle, so split the chain at the entry, drop it off the front
+    // of the second half and stitch the two halves back together
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.bucket_index(key);
+        let bucket = &mut self.buckets[index];
+        let position = bucket.iter().position(|(k, _)| k.borrow() == key)?;
+        let mut rest = bucket.split_off(position);
+        let (_, value) = rest.pop_front()?;
+        bucket.append(&mut rest);
+        self.length -= 1;
+        Some(value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    fn empty_buckets(count: usize) -> Vec<LinkedList<(K, V)>> {
+        (0..count).map(|_| LinkedList::new()).collect()
+    }
+
+    fn bucket_index<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        (hasher.finish() % self.buckets.len() as u64) as usize
+    }
+
+    // Entries move over node by node, nothing gets cloned
+    fn grow(&mut self) {
+        let doubled = Self::empty_buckets(self.buckets.len() * 2);
+        let old = std::mem::replace(&mut self.buckets, doubled);
+        for mut bucket in old {
+            while let Some((key, value)) = bucket.pop_front() {
+                let index = self.bucket_index(&key);
+                self.buckets[index].push_back((key, value));
+            }
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for ChainedHashMap<K, V> {
+    fn default() -> Self {
+        ChainedHashMap::new()
+    }
+}
+
+#[cfg(test)]
+mod hash_map_tests {
+    use super::*;
+
+    // Every key hashes the same, so they all pile up in one bucket
+    #[derive(PartialEq, Eq, Debug)]
+    struct Colliding(u32);
+
+    impl Hash for Colliding {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            0u8.hash(state);
+        }
+    }
+
+    #[test]
+    fn test_collisions() {
+        let mut map = ChainedHashMap::new();
+        for i in 0..5 {
+            map.insert(Colliding(i), i * 10);
+        }
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.buckets.iter().filter(|b| !b.is_empty()).count(), 1);
+        for i in 0..5 {
+            assert_eq!(map.get(&Colliding(i)), Some(&(i * 10)));
+        }
+        assert_eq!(map.get(&Colliding(5)), None);
+
+        // Out of the middle of the chain, the rest of it stays reachable
+        assert_eq!(map.remove(&Colliding(2)), Some(20));
+        assert_eq!(map.get(&Colliding(2)), None);
+        assert_eq!(map.get(&Colliding(1)), Some(&10));
+        assert_eq!(map.get(&Colliding(3)), Some(&30));
+        assert_eq!(map.len(), 4);
+    }
+
+    #[test]
+    fn test_reinsert_updates() {
+        let mut map = ChainedHashMap::new();
+        assert_eq!(map.insert(String::from("a"), 1), None);
+        assert_eq!(map.insert(String::from("a"), 2), Some(1));
+        assert_eq!(map.get("a"), Some(&2));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut map = ChainedHashMap::new();
+        map.insert(String::from("a"), 1);
+        map.insert(String::from("b"), 2);
+        assert_eq!(map.remove("a"), Some(1));
+        assert_eq!(map.remove("a"), None);
+        assert!(!map.contains_key("a"));
+        assert!(map.contains_key("b"));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.remove("b"), Some(2));
+        assert!(map.is_empty());
+
+        let mut empty: ChainedHashMap<u8, u8> = ChainedHashMap::default();
+        assert_eq!(empty.remove(&1), None);
+        assert_eq!(empty.get(&1), None);
+    }
+
+    #[test]
+    fn test_grows_past_load_factor() {
+        let mut map = ChainedHashMap::new();
+        for i in 0..6 {
+            map.insert(i, i);
+        }
+        assert_eq!(map.buckets.len(), INITIAL_BUCKETS); // 6 of 8 is right at 0.75
+        map.insert(6, 6);
+        assert_eq!(map.buckets.len(), INITIAL_BUCKETS * 2);
+
+        for i in 7..10_000 {
+            map.insert(i, i);
+        }
+        assert_eq!(map.len(), 10_000);
+        assert!(map.len() * 4 <= map.buckets.len() * 3);
+        for i in 0..10_000 {
+            assert_eq!(map.get(&i), Some(&i));
+        }
+    }
+}
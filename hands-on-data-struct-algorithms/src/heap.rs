@@ -0,0 +1,134 @@
+// A binary heap laid out in a Vec: the children of index i live at 2i + 1 and 2i + 2, so there are no pointers at all.
+// The smallest value is always at index 0
+pub struct MinHeap<T: Ord> {
+    values: Vec<T>,
+}
+
+impl<T: Ord> MinHeap<T> {
+    pub fn new() -> MinHeap<T> {
+        MinHeap { values: Vec::new() }
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.values.push(value);
+        self.sift_up(self.values.len() - 1);
+    }
+
+    // Swaps the last value into the root so the Vec only ever shrinks from the end, then lets it sink back down
+    pub fn pop(&mut self) -> Option<T> {
+        if self.values.is_empty() {
+            return None;
+        }
+        let last = self.values.len() - 1;
+        self.values.swap(0, last);
+        let min = self.values.pop();
+        self.sift_down(0);
+        min
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.values.first()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.values[index] >= self.values[parent] {
+                break;
+            }
+            self.values.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        let len = self.values.len();
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut smallest = index;
+            if left < len && self.values[left] < self.values[smallest] {
+                smallest = left;
+            }
+            if right < len && self.values[right] < self.values[smallest] {
+                smallest = right;
+            }
+            if smallest == index {
+                break;
+            }
+            self.values.swap(index, smallest);
+            index = smallest;
+        }
+    }
+}
+
+impl<T: Ord> Default for MinHeap<T> {
+    fn default() -> Self {
+        MinHeap::new()
+    }
+}
+
+#[cfg(test)]
+mod heap_tests {
+    use super::*;
+
+    #[test]
+    fn test_pops_in_ascending_order() {
+        // Same cheap shuffle as the sorting test over in lists
+        let mut seed: u64 = 7;
+        let mut values: Vec<u32> = (0..1_000).collect();
+        for i in (1..values.len()).rev() {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            values.swap(i, (seed >> 33) as usize % (i + 1));
+        }
+        let mut heap = MinHeap::new();
+        for value in values {
+            heap.push(value);
+        }
+        assert_eq!(heap.len(), 1_000);
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, (0..1_000).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut heap = MinHeap::new();
+        heap.push(5);
+        assert_eq!(heap.peek(), Some(&5));
+        heap.push(8);
+        assert_eq!(heap.peek(), Some(&5));
+        heap.push(2);
+        assert_eq!(heap.peek(), Some(&2));
+        heap.push(2); // duplicates are fine
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.peek(), Some(&2));
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.peek(), Some(&5));
+        assert_eq!(heap.len(), 2);
+    }
+
+    #[test]
+    fn test_empty_heap() {
+        let mut heap: MinHeap<String> = MinHeap::default();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+        assert_eq!(heap.len(), 0);
+        heap.push(String::from("only"));
+        assert_eq!(heap.pop(), Some(String::from("only")));
+        assert!(heap.is_empty());
+    }
+}
@@ -0,0 +1,23 @@
+//! Naive implementations of the data structures from the book, written while following along.
+//!
+//! ```
+//! use hands_on_data_struct_algorithms::lists::BetterTransactionLog;
+//!
+//! let mut log = BetterTransactionLog::new_empty();
+//! log.append(String::from("INSERT INTO mytable VALUES (1,2,3)"));
+//! log.append(String::from("INSERT INTO mytable VALUES (2,3,4)"));
+//! assert_eq!(log.len(), 2);
+//! assert_eq!(log.pop(), Some(String::from("INSERT INTO mytable VALUES (1,2,3)")));
+//! assert_eq!(log.len(), 1);
+//! ```
+
+pub mod bst;
+pub mod hash_map;
+pub mod heap;
+pub mod lists;
+pub mod queue;
+pub mod shared;
+pub mod skip_list;
+pub mod stack;
+pub mod trie;
+pub mod undo;
@@ -1,46 +1,217 @@
-use std::cell::RefCell;
-use std::fmt::{Debug, Formatter};
-use std::rc::Rc;
+use std::cell::{Cell, Ref, RefCell};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io::{self, Read, Write};
+use std::iter::{FusedIterator, Rev};
+use std::marker::PhantomData;
+use std::rc::{Rc, Weak};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-type Link = Option<Rc<RefCell<Node>>>;
+type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+// Back-links are weak so a node is only ever owned by its predecessor (or head). Strong prev pointers made reference cycles
+type WeakLink<T> = Option<Weak<RefCell<Node<T>>>>;
 
-#[derive(PartialEq, Clone)]
-struct Node {
-    value: String,
-    next: Link,
-    prev: Link,
+#[derive(Clone)]
+pub struct Node<T> {
+    value: T,
+    next: Link<T>,
+    prev: WeakLink<T>,
+    timestamp: u64, // milliseconds since the unix epoch, from when the node was made unless append_at said otherwise
+    seq: u64,       // handed out by BetterTransactionLog, see take_seq
+    hash: u64,      // only filled in by append_hashed, see verify
+    chained_from: u64, // the previous entry's hash at the time this one was appended
+    origin: Rc<Origin>, // which log the node is in, see Origin
 }
 
-#[derive(Debug)]
-struct TransactionLog {
-    head: Link,
-    tail: Link,
-    pub length: u64,
+// Says which log a node belongs to and how far its sequence number has moved since it was handed out.
+// Every log has its own root Origin and stamps it on each node it links in. When append_log, merge_sorted or splice
+// take over another log's nodes, that log's root gets hung under ours along with a shift that lifts its numbers
+// clear of ours, instead of touching every node, so handing over a whole chain stays O(1).
+// Following the parents up to the root tells which log a node is in now, and adding up the shifts on the way
+// gives its number in that log. A node that was never in a log just has a root of its own
+#[derive(Default)]
+struct Origin {
+    parent: RefCell<Option<Rc<Origin>>>,
+    shift: Cell<u64>, // added to the numbers of every node under this one, a root's is always 0
 }
 
-#[derive(Debug, Clone)]
-struct BetterTransactionLog {
-    head: Link,
-    tail: Link,
-    pub length: u64,
+impl Origin {
+    // The root and the total shift on the way there. Everything passed on the way gets pointed straight at the root
+    // with its own total, so the next lookup is a single hop
+    fn resolve(origin: &Rc<Origin>) -> (Rc<Origin>, u64) {
+        let mut path = Vec::new();
+        let mut current = origin.clone();
+        loop {
+            let parent = current.parent.borrow().clone();
+            match parent {
+                Some(parent) => {
+                    path.push(current);
+                    current = parent;
+                }
+                None => break,
+            }
+        }
+        let mut total = 0;
+        for visited in path.into_iter().rev() {
+            total += visited.shift.get();
+            visited.shift.set(total);
+            *visited.parent.borrow_mut() = Some(current.clone());
+        }
+        (current, total)
+    }
+}
+
+// A clock set before 1970 just gets 0, it's only used for ordering entries anyway
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+// 64 bit FNV-1a: xor in a byte, multiply by the prime, repeat. Tiny and good enough to notice an edited entry,
+// but it's no defence against someone who recomputes the hashes after tampering
+struct Fnv1a(u64);
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+// hash(previous hash ++ value), which is what ties each entry to everything before it
+fn chain_hash<T: Hash>(previous: u64, value: &T) -> u64 {
+    let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+    hasher.write_u64(previous);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+pub struct TransactionLog<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    length: usize,
+}
+
+pub struct BetterTransactionLog<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    length: usize,
+    capacity: Option<usize>, // None means unbounded
+    next_seq: u64,
+    origin: Rc<Origin>, // stamped on every node in this log, see Origin
+}
+
+impl<T: Clone> Node<T> {
+    // An outstanding ListIteratorTracker can still hold a clone of a node we just unlinked, so try_unwrap is not guaranteed to work.
+    // When someone else is still looking at the node we leave it to them and just copy the value out
+    fn into_value(node: Rc<RefCell<Node<T>>>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(node) => node.into_inner().value, // Basically "unwrapping" the RefCell
+            Err(node) => node.borrow().value.clone(),
+        }
+    }
+}
+
+// Handed back when an index doesn't point inside (or at the very end of) a log
+#[derive(Debug, PartialEq)]
+pub struct OutOfBounds {
+    index: usize,
+    length: usize,
+}
+
+impl Display for OutOfBounds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds for a log of length {}",
+            self.index, self.length
+        )
+    }
+}
+
+impl Error for OutOfBounds {}
+
+// Handed back by try_pop when there is nothing left to pop
+#[derive(Debug, PartialEq)]
+pub struct EmptyLogError;
+
+impl Display for EmptyLogError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cannot pop from an empty log")
+    }
+}
+
+impl Error for EmptyLogError {}
+
+// What debug_stats found on a walk over the nodes. In a healthy log `nodes` equals `length`, and every node
+// is owned exactly once by whatever comes before it, plus once more by `tail` for the last one.
+// So anything outside 1..=2 for the strong counts means something else is hanging on to a node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogStats {
+    pub nodes: usize,
+    pub length: usize,
+    pub value_bytes: usize,
+    pub min_strong: usize,
+    pub max_strong: usize,
+}
+
+// One step of zip_longest. Once the shorter log runs out, the rest of the longer one comes through on its own
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZipEntry<L, R> {
+    Both(L, R),
+    LeftOnly(L),
+    RightOnly(R),
 }
 
-impl Node {
-    pub fn new(value: String) -> Rc<RefCell<Node>> {
+impl<T> Node<T> {
+    // The raw seq is what the node was numbered in the log it was made in, this is its number in the log it's in now
+    fn seq(&self) -> u64 {
+        self.seq + Origin::resolve(&self.origin).1
+    }
+
+    pub fn new(value: T) -> Rc<RefCell<Node<T>>> {
+        Node::new_at(value, now_millis())
+    }
+
+    pub fn new_with(value: T, next: Link<T>, prev: WeakLink<T>) -> Rc<RefCell<Node<T>>> {
         Rc::new(RefCell::new(Node {
             value,
-            next: None,
-            prev: None,
+            next,
+            prev,
+            timestamp: now_millis(),
+            seq: 0,
+            hash: 0,
+            chained_from: 0,
+            origin: Rc::default(),
         }))
     }
 
-    pub fn new_with(value: String, next: Link, prev: Link) -> Rc<RefCell<Node>> {
-        Rc::new(RefCell::new(Node { value, next, prev }))
+    fn new_at(value: T, timestamp: u64) -> Rc<RefCell<Node<T>>> {
+        Rc::new(RefCell::new(Node {
+            value,
+            next: None,
+            prev: None,
+            timestamp,
+            seq: 0,
+            hash: 0,
+            chained_from: 0,
+            origin: Rc::default(),
+        }))
     }
 }
 
-impl TransactionLog {
-    pub fn new_empty() -> TransactionLog {
+impl<T> TransactionLog<T> {
+    pub fn new_empty() -> TransactionLog<T> {
         TransactionLog {
             head: None,
             tail: None,
@@ -48,7 +219,19 @@ impl TransactionLog {
         }
     }
 
-    pub fn append(&mut self, value: String) {
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none() && self.tail.is_none()
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    pub fn append(&mut self, value: T) {
         let node = Node::new(value);
         match self.tail.take() {
             None => {
@@ -62,7 +245,32 @@ impl TransactionLog {
         self.length += 1;
     }
 
-    pub fn pop(&mut self) -> Option<String> {
+    // Links the new entries up among themselves first, then hooks the whole chain onto the tail in one go
+    pub fn append_all<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        let mut chain_head: Link<T> = None;
+        let mut chain_tail: Link<T> = None;
+        let mut count = 0;
+        for value in values {
+            let node = Node::new(value);
+            match chain_tail.take() {
+                None => chain_head = Some(node.clone()),
+                Some(tail) => tail.borrow_mut().next = Some(node.clone()),
+            }
+            chain_tail = Some(node);
+            count += 1;
+        }
+        let Some(chain_head) = chain_head else {
+            return;
+        };
+        match self.tail.take() {
+            None => self.head = Some(chain_head),
+            Some(tail) => tail.borrow_mut().next = Some(chain_head),
+        }
+        self.tail = chain_tail;
+        self.length += count;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
         self.head.take().map(|head| {
             if let Some(next) = head.borrow_mut().next.take() {
                 self.head = Some(next);
@@ -71,215 +279,4396 @@ impl TransactionLog {
             }
             self.length -= 1;
             Rc::try_unwrap(head)
-                .expect("It should just work")
+                .unwrap_or_else(|_| panic!("It should just work")) // expect() would need T: Debug to print the Err
                 .into_inner() // Basically "unwrapping" the RefCell
                 .value
         })
     }
+
+    pub fn try_pop(&mut self) -> Result<T, EmptyLogError> {
+        self.pop().ok_or(EmptyLogError)
+    }
+
+    // Lets you look at the oldest entry without paying for a clone
+    pub fn peek_with<R, F: FnOnce(&T) -> R>(&self, f: F) -> Option<R> {
+        self.head.as_ref().map(|head| f(&head.borrow().value))
+    }
+
+    pub fn peek_back_with<R, F: FnOnce(&T) -> R>(&self, f: F) -> Option<R> {
+        self.tail.as_ref().map(|tail| f(&tail.borrow().value))
+    }
+}
+
+impl<T: Clone> TransactionLog<T> {
+    pub fn peek(&self) -> Option<T> {
+        self.peek_with(T::clone)
+    }
+
+    pub fn peek_back(&self) -> Option<T> {
+        self.peek_back_with(T::clone)
+    }
+
+    // Iterator-style name for the newest entry, it's just the tail so no walking
+    pub fn last(&self) -> Option<T> {
+        self.peek_back()
+    }
 }
 
-impl BetterTransactionLog {
-    pub fn new_empty() -> BetterTransactionLog {
+impl<T> BetterTransactionLog<T> {
+    pub fn new_empty() -> BetterTransactionLog<T> {
         BetterTransactionLog {
             head: None,
             tail: None,
             length: 0,
+            capacity: None,
+            next_seq: 0,
+            origin: Rc::default(),
         }
     }
 
-    pub fn append(&mut self, value: String) {
-        let node = Node::new(value);
+    // A rolling buffer: once `capacity` entries are in, adding another evicts whatever sits at the head.
+    // That goes for every way of adding entries, see evict_overflow. Pushing onto the front of a full log
+    // therefore drops the new entry straight away, since it's the one at the head.
+    // A capacity of 0 would mean every append throws its own entry straight away, which is surely a mistake, so it panics
+    pub fn with_capacity(capacity: usize) -> BetterTransactionLog<T> {
+        if capacity == 0 {
+            panic!("a log needs a capacity of at least 1");
+        }
+        BetterTransactionLog {
+            head: None,
+            tail: None,
+            length: 0,
+            capacity: Some(capacity),
+            next_seq: 0,
+            origin: Rc::default(),
+        }
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    fn is_full(&self) -> bool {
+        self.capacity
+            .is_some_and(|capacity| self.length >= capacity)
+    }
+
+    // Drops entries off the front until the log fits its capacity again.
+    // Everything that links new nodes in finishes with this, so no path can leave the log over its bound
+    fn evict_overflow(&mut self) {
+        while self.capacity.is_some_and(|capacity| self.length > capacity) {
+            let Some(head) = self.head.clone() else {
+                break;
+            };
+            self.unlink(&head);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none() && self.tail.is_none()
+    }
+
+    pub fn clear(&mut self) {
+        self.tail.take();
+        Self::free_chain(self.head.take());
+        self.length = 0;
+    }
+
+    // Keeps the first `len` entries and throws the rest away
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.length {
+            return;
+        }
+        if len == 0 {
+            self.clear();
+            return;
+        }
+        let new_tail = self.node_at(len - 1).expect("len is within the log");
+        let discarded = new_tail.borrow_mut().next.take();
+        self.tail = Some(new_tail);
+        self.length = len;
+        Self::free_chain(discarded);
+    }
+
+    // Runs every entry through `apply`, oldest first, and says how many went through.
+    // If one fails, the error comes back with that entry's index so the replay can pick up again there with replay_from
+    pub fn replay<E, F: FnMut(&T) -> Result<(), E>>(&self, apply: F) -> Result<usize, (usize, E)> {
+        self.replay_from(0, apply)
+    }
+
+    // Same as replay but starting at `index`. The count is only what this call got through, the error index is from the head
+    pub fn replay_from<E, F: FnMut(&T) -> Result<(), E>>(
+        &self,
+        index: usize,
+        mut apply: F,
+    ) -> Result<usize, (usize, E)> {
+        let mut current = self.node_at(index);
+        let mut applied = 0;
+        while let Some(node) = current {
+            apply(&node.borrow().value).map_err(|err| (index + applied, err))?;
+            applied += 1;
+            current = node.borrow().next.clone();
+        }
+        Ok(applied)
+    }
+
+    // Acks everything before `seq`: drops every entry numbered below it, plus anything sitting in front of the `seq` entry
+    // itself if it's still here. push_front, insert and the reordering methods can put any number anywhere, so this walks
+    // the whole log rather than stopping early. Higher numbered entries in front of the target are held back until we
+    // know the target is actually in the log
+    pub fn pop_until(&mut self, seq: u64) {
+        let mut ahead = Vec::new();
+        let mut found = false;
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            current = node.borrow().next.clone();
+            let node_seq = node.borrow().seq();
+            if node_seq < seq {
+                self.unlink(&node);
+            } else if node_seq == seq {
+                found = true;
+                for earlier in ahead.drain(..) {
+                    self.unlink(&earlier);
+                }
+            } else if !found {
+                ahead.push(node);
+            }
+        }
+    }
+
+    // pop() needs T: Clone for its fallback, so walk the nodes by hand and sever both links on each one before letting it go.
+    // Any node an old iterator still holds just gets detached from the rest instead of dragging the chain along with it
+    fn free_chain(mut current: Link<T>) {
+        while let Some(node) = current {
+            node.borrow_mut().prev.take();
+            current = node.borrow_mut().next.take();
+        }
+    }
+
+    // Every new entry gets the next sequence number. Unlike an index it sticks with the entry for as long as it stays
+    // in this log, and no two entries in a log ever share one. The numbers say what order entries arrived in,
+    // not where they sit, since push_front, insert and the reordering methods can put them anywhere.
+    // Moving to another log gives an entry a new number there, see adopt and split_at, so there can be gaps
+    fn take_seq(&mut self) -> u64 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        seq
+    }
+
+    // Numbers a node that's about to be linked into this log and marks it as ours
+    fn stamp(&mut self, node: &Rc<RefCell<Node<T>>>) -> u64 {
+        let seq = self.take_seq();
+        let mut node = node.borrow_mut();
+        node.seq = seq;
+        node.origin = self.origin.clone();
+        seq
+    }
+
+    // Every node of `other` is about to become ours. Rather than touch them all, its root goes under ours.
+    // Everything other ever numbered is below its next_seq, so shifting by our next_seq puts its entries past all
+    // of ours and keeps them in the order they had. Our counter then skips the whole block
+    fn adopt(&mut self, other: &BetterTransactionLog<T>) {
+        other.origin.shift.set(self.next_seq);
+        *other.origin.parent.borrow_mut() = Some(self.origin.clone());
+        self.next_seq += other.next_seq;
+    }
+
+    // Returns the new entry's sequence number, see get_by_seq and pop_until
+    pub fn append(&mut self, value: T) -> u64 {
+        self.append_at(value, now_millis())
+    }
+
+    // append with a timestamp of your choosing instead of the clock, in milliseconds since the epoch
+    pub fn append_at(&mut self, value: T, millis: u64) -> u64 {
+        let node = Node::new_at(value, millis);
+        let seq = self.stamp(&node);
         match self.tail.take() {
             None => {
                 self.head = Some(node.clone());
             }
             Some(tail) => {
                 tail.borrow_mut().next = Some(node.clone());
-                node.borrow_mut().prev = Some(tail);
+                node.borrow_mut().prev = Some(Rc::downgrade(&tail));
+            }
+        }
+        self.tail = Some(node);
+        self.length += 1;
+        self.evict_overflow();
+        seq
+    }
+
+    // Builds the new entries into a chain of their own and splices it on with append_log, which numbers them,
+    // so the tail and length only get touched once. A bounded log then drops whatever no longer fits off the front,
+    // which leaves the same entries behind as appending one at a time would
+    pub fn append_all<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        let chain: BetterTransactionLog<T> = values.into_iter().collect();
+        self.append_log(chain);
+    }
+
+    // Mirror image of append: the new node becomes the head and the old head points back at it
+    pub fn push_front(&mut self, value: T) {
+        let node = Node::new(value);
+        self.stamp(&node);
+        match self.head.take() {
+            None => {
+                self.tail = Some(node.clone());
+            }
+            Some(head) => {
+                head.borrow_mut().prev = Some(Rc::downgrade(&node));
+                node.borrow_mut().next = Some(head);
+            }
+        }
+        self.head = Some(node);
+        self.length += 1;
+        self.evict_overflow();
+    }
+
+    // Appends and hands back a handle that can remove this exact entry later without searching for it, see remove()
+    pub fn append_with_handle(&mut self, value: T) -> NodeHandle<T> {
+        self.append(value);
+        let tail = self.tail.as_ref().expect("we just appended");
+        NodeHandle {
+            node: Rc::downgrade(tail),
+        }
+    }
+
+    // A handle to whatever is at the head right now, for walking a log handle by handle, see NodeHandle::next
+    pub fn front_handle(&self) -> Option<NodeHandle<T>> {
+        self.head.as_ref().map(|head| NodeHandle {
+            node: Rc::downgrade(head),
+        })
+    }
+
+    fn owns(&self, node: &Rc<RefCell<Node<T>>>) -> bool {
+        Rc::ptr_eq(&Origin::resolve(&node.borrow().origin).0, &self.origin)
+    }
+
+    // True if the node is still linked into a log: either something points at it through next, or it's our head
+    fn is_linked(&self, node: &Rc<RefCell<Node<T>>>) -> bool {
+        match node.borrow().prev.as_ref().and_then(Weak::upgrade) {
+            Some(prev) => matches!(&prev.borrow().next, Some(next) if Rc::ptr_eq(next, node)),
+            None => matches!(&self.head, Some(head) if Rc::ptr_eq(head, node)),
+        }
+    }
+
+    pub fn peek_with<R, F: FnOnce(&T) -> R>(&self, f: F) -> Option<R> {
+        self.head.as_ref().map(|head| f(&head.borrow().value))
+    }
+
+    pub fn peek_back_with<R, F: FnOnce(&T) -> R>(&self, f: F) -> Option<R> {
+        self.tail.as_ref().map(|tail| f(&tail.borrow().value))
+    }
+
+    // Walks the log without cloning any values, see ValueRef
+    pub fn iter_refs(&self) -> RefIter<T> {
+        RefIter {
+            current: self.head.clone(),
+        }
+    }
+
+    /// Looks at one entry without cloning it. A plain `&T` would have to skip the RefCell's bookkeeping,
+    /// so this hands back the node itself and the value gets borrowed through a guard:
+    ///
+    /// ```
+    /// use hands_on_data_struct_algorithms::lists::BetterTransactionLog;
+    ///
+    /// let mut log = BetterTransactionLog::from(vec![String::from("BEGIN"), String::from("COMMIT")]);
+    /// let first = log.get_ref(0).unwrap();
+    /// assert_eq!(*first.value(), "BEGIN");
+    /// assert_eq!(log.get_ref(1).unwrap().value().len(), 6);
+    /// assert!(log.get_ref(2).is_none());
+    /// ```
+    ///
+    /// The log isn't borrowed by it, so it can still change while you hold on to an entry. Popping it just
+    /// hands back a clone, and the entry you're holding stays readable:
+    ///
+    /// ```
+    /// # use hands_on_data_struct_algorithms::lists::BetterTransactionLog;
+    /// let mut log = BetterTransactionLog::from(vec![1, 2]);
+    /// let first = log.get_ref(0).unwrap();
+    /// assert_eq!(log.pop(), Some(1));
+    /// assert_eq!(*first.value(), 1);
+    /// ```
+    ///
+    /// Reaching the index walks from whichever end is closer, so it's O(n) like `get`.
+    ///
+    /// This is also why there's no `Index<usize>` impl. `Index::index` has to hand out a bare `&T` tied to `&self`,
+    /// and the only way to get one out of a `RefCell` node without keeping a guard alive is unsafe. Nothing would
+    /// then stop `map_values` writing to the value, or `pop` freeing the node, while that reference is still in use.
+    /// So out of range is a `None` here rather than the panic `log[i]` would give:
+    ///
+    /// ```
+    /// # use hands_on_data_struct_algorithms::lists::BetterTransactionLog;
+    /// let log = BetterTransactionLog::from(vec!["only"]);
+    /// assert!(log.get_ref(1).is_none());
+    /// ```
+    pub fn get_ref(&self, index: usize) -> Option<ValueRef<T>> {
+        self.node_at(index).map(|node| ValueRef { node })
+    }
+
+    // A front to back walk that doesn't keep anything alive, so the log can be popped while it's around, see WeakIter
+    pub fn weak_iter(&self) -> WeakIter<T> {
+        WeakIter {
+            next: self.head.as_ref().map_or_else(Weak::new, Rc::downgrade),
+        }
+    }
+
+    // Borrow<Q> lets a log of Strings be searched with a plain &str, same as HashMap::get.
+    // We can't `use std::borrow::Borrow` though, it would shadow RefCell::borrow on every Rc in this file
+    pub fn find<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let mut current = self.head.clone();
+        let mut index = 0;
+        while let Some(node) = current {
+            let node = node.borrow();
+            if <T as std::borrow::Borrow<Q>>::borrow(&node.value) == value {
+                return Some(index);
+            }
+            current = node.next.clone();
+            index += 1;
+        }
+        None
+    }
+
+    // Same as find but walks back from the tail, so it's the most recent match.
+    // The index still counts from the head
+    pub fn rfind<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let mut current = self.tail.clone();
+        let mut index = self.length;
+        while let Some(node) = current {
+            index -= 1;
+            let node = node.borrow();
+            if <T as std::borrow::Borrow<Q>>::borrow(&node.value) == value {
+                return Some(index);
+            }
+            current = node.prev.as_ref().and_then(Weak::upgrade);
+        }
+        None
+    }
+
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.find(value).is_some()
+    }
+
+    // Same answer as slice::binary_search on a sorted log: Ok with where the value is (any one of them if it's in
+    // there more than once), or Err with where it would have to go to keep the log sorted.
+    // There's no jumping into the middle of a linked list, so it first walks the whole thing once to collect the nodes
+    // into a Vec. That makes it O(n) overall, just with only O(log n) comparisons
+    pub fn binary_search<Q>(&self, value: &Q) -> Result<usize, usize>
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut nodes = Vec::with_capacity(self.length);
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            current = node.borrow().next.clone();
+            nodes.push(node);
+        }
+        nodes.binary_search_by(|node| {
+            <T as std::borrow::Borrow<Q>>::borrow(&node.borrow().value).cmp(value)
+        })
+    }
+
+    // How many entries are equal to value. Returns a usize like len() does
+    pub fn count<Q>(&self, value: &Q) -> usize
+    where
+        T: std::borrow::Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let mut current = self.head.clone();
+        let mut count = 0;
+        while let Some(node) = current {
+            let node = node.borrow();
+            if <T as std::borrow::Borrow<Q>>::borrow(&node.value) == value {
+                count += 1;
+            }
+            current = node.next.clone();
+        }
+        count
+    }
+
+    // Splices the value in so that it ends up at `index`, shuffling everything from there on back by one
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), OutOfBounds> {
+        if index > self.length {
+            return Err(OutOfBounds {
+                index,
+                length: self.length,
+            });
+        }
+        if index == 0 {
+            self.push_front(value);
+            return Ok(());
+        }
+        if index == self.length {
+            self.append(value);
+            return Ok(());
+        }
+        // Somewhere in the middle, so both neighbours exist
+        let after = self.node_at(index).expect("index is within the log");
+        let before = after
+            .borrow()
+            .prev
+            .as_ref()
+            .and_then(Weak::upgrade)
+            .expect("only the head has no prev");
+        let node = Node::new_with(value, Some(after.clone()), Some(Rc::downgrade(&before)));
+        self.stamp(&node);
+        after.borrow_mut().prev = Some(Rc::downgrade(&node));
+        before.borrow_mut().next = Some(node);
+        self.length += 1;
+        self.evict_overflow();
+        Ok(())
+    }
+
+    // Every node already knows both neighbours, so flipping them round and swapping the ends is all it takes.
+    // prev is weak though, so we carry a strong handle to the previous node ourselves:
+    // once its next is taken, nothing else owns it until the following node points back at it
+    pub fn reverse(&mut self) {
+        let mut previous: Link<T> = None;
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            let next = node.borrow_mut().next.take();
+            node.borrow_mut().next = previous.take();
+            node.borrow_mut().prev = next.as_ref().map(Rc::downgrade);
+            previous = Some(node);
+            current = next;
+        }
+        std::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    // Hooks the other log's nodes onto our tail in O(1). Both ends are known, so nothing gets walked,
+    // and the incoming entries are renumbered through their origin rather than one by one, see adopt
+    pub fn append_log(&mut self, mut other: BetterTransactionLog<T>) {
+        let Some(other_head) = other.head.take() else {
+            return;
+        };
+        self.adopt(&other);
+        match self.tail.take() {
+            None => {
+                self.head = Some(other_head);
+            }
+            Some(tail) => {
+                other_head.borrow_mut().prev = Some(Rc::downgrade(&tail));
+                tail.borrow_mut().next = Some(other_head);
+            }
+        }
+        self.tail = other.tail.take();
+        self.length += other.length;
+        other.length = 0;
+        self.evict_overflow();
+    }
+
+    // Everything from `index` onwards moves into the returned log, self keeps the first `index` entries.
+    // No nodes get copied, we just cut the link between the two halves. The moved entries are in a new log now,
+    // so they get walked once to be stamped as its own and numbered from 0 in the order they sit.
+    // Keeping their old numbers would leave both halves with the same range, and every rejoin would double it
+    pub fn split_at(&mut self, index: usize) -> BetterTransactionLog<T> {
+        if index > self.length {
+            panic!(
+                "index {} is out of bounds for a log of length {}",
+                index, self.length
+            );
+        }
+        let mut other = BetterTransactionLog::new_empty();
+        if index == self.length {
+            return other;
+        }
+        if index == 0 {
+            // The whole log moves as it is, numbers and all, and we carry on counting from where we were
+            std::mem::swap(self, &mut other);
+            self.capacity = other.capacity.take(); // the bound belongs to this log, not to whatever got split off
+            self.next_seq = other.next_seq;
+            return other;
+        }
+        let new_head = self.node_at(index).expect("index is within the log");
+        let new_tail = new_head
+            .borrow_mut()
+            .prev
+            .take()
+            .and_then(|prev| prev.upgrade())
+            .expect("only the head has no prev");
+        new_tail.borrow_mut().next.take();
+        other.head = Some(new_head);
+        other.tail = self.tail.replace(new_tail);
+        other.length = self.length - index;
+        self.length = index;
+        let mut current = other.head.clone();
+        while let Some(node) = current {
+            other.stamp(&node);
+            current = node.borrow().next.clone();
+        }
+        other
+    }
+
+    // Swaps every value for what the closure makes of it, without touching the links. Timestamps and sequence
+    // numbers stay with their nodes, but a hashed log will fail verify afterwards since the values changed
+    pub fn map_values<F: FnMut(&T) -> T>(&mut self, mut f: F) {
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            let mut node = node.borrow_mut();
+            node.value = f(&node.value);
+            current = node.next.clone();
+        }
+    }
+
+    // Keeps only the entries the closure says yes to, unlinking the rest as we go
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            current = node.borrow().next.clone();
+            if !keep(&node.borrow().value) {
+                self.unlink(&node);
+            }
+        }
+    }
+
+    // Collapses runs of equal neighbours down to their first entry, like Vec::dedup
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    // `same` gets the entry being looked at and the last one we kept, in that order
+    pub fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same: F) {
+        let Some(mut kept) = self.head.clone() else {
+            return;
+        };
+        let mut current = kept.borrow().next.clone();
+        while let Some(node) = current {
+            current = node.borrow().next.clone();
+            if same(&node.borrow().value, &kept.borrow().value) {
+                self.unlink(&node);
+            } else {
+                kept = node;
+            }
+        }
+    }
+
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    // A merge sort on the nodes themselves, nothing gets copied into a Vec and back.
+    // While sorting we only follow next and treat the list as singly linked, then fix up prev and tail in one last walk
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        self.tail.take();
+        self.head = Self::merge_sort(self.head.take(), self.length, &mut compare);
+        self.relink_backwards();
+    }
+
+    // Trades the entries at i and j by moving the nodes themselves, so each entry takes its timestamp,
+    // sequence number and hash along, and any NodeHandle keeps pointing at the same entry.
+    // All four neighbours get picked up before anything is rewired. Neighbouring entries are their own
+    // special case because there a's next is b and b's prev is a, so there's nothing in between to reattach
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<(), OutOfBounds> {
+        for index in [i, j] {
+            if index >= self.length {
+                return Err(OutOfBounds {
+                    index,
+                    length: self.length,
+                });
+            }
+        }
+        let (i, j) = (i.min(j), i.max(j));
+        if i == j {
+            return Ok(());
+        }
+        let a = self.node_at(i).expect("checked above");
+        let b = self.node_at(j).expect("checked above");
+        let before_a = a.borrow().prev.as_ref().and_then(Weak::upgrade);
+        let after_b = b.borrow().next.clone();
+        if j == i + 1 {
+            self.join(before_a, Some(b.clone()));
+            self.join(Some(b), Some(a.clone()));
+            self.join(Some(a), after_b);
+        } else {
+            let after_a = a.borrow().next.clone();
+            let before_b = b.borrow().prev.as_ref().and_then(Weak::upgrade);
+            self.join(before_a, Some(b.clone()));
+            self.join(Some(b), after_a);
+            self.join(before_b, Some(a.clone()));
+            self.join(Some(a), after_b);
+        }
+        Ok(())
+    }
+
+    // Moves the first n entries round to the back. Only the ends get relinked: the old tail points at the old head,
+    // and the list gets cut just before entry n. Rotating by a whole number of laps changes nothing, so n wraps around
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.length == 0 || n.is_multiple_of(self.length) {
+            return;
+        }
+        let new_head = self
+            .node_at(n % self.length)
+            .expect("n % len is within the log");
+        let new_tail = new_head
+            .borrow_mut()
+            .prev
+            .take()
+            .and_then(|prev| prev.upgrade())
+            .expect("only the head has no prev");
+        new_tail.borrow_mut().next.take();
+        let old_head = self.head.replace(new_head).expect("the log isn't empty");
+        let old_tail = self.tail.replace(new_tail).expect("the log isn't empty");
+        old_head.borrow_mut().prev = Some(Rc::downgrade(&old_tail));
+        old_tail.borrow_mut().next = Some(old_head);
+    }
+
+    // Two already sorted logs become one sorted log. The nodes are spliced together as they are, nothing is reallocated.
+    // Ties go to self's entries first, same as the sort. Other's entries get renumbered like in append_log
+    pub fn merge_sorted(mut self, mut other: BetterTransactionLog<T>) -> BetterTransactionLog<T>
+    where
+        T: Ord,
+    {
+        self.adopt(&other);
+        self.tail.take();
+        other.tail.take();
+        self.head = Self::merge(self.head.take(), other.head.take(), &mut T::cmp);
+        self.length += other.length;
+        other.length = 0;
+        self.relink_backwards();
+        self.evict_overflow();
+        self
+    }
+
+    // For when the next links are right but prev and tail aren't: one walk from the head sets them all
+    fn relink_backwards(&mut self) {
+        let mut previous: Link<T> = None;
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            node.borrow_mut().prev = previous.as_ref().map(Rc::downgrade);
+            current = node.borrow().next.clone();
+            previous = Some(node);
+        }
+        self.tail = previous;
+    }
+
+    // Only recurses log2(len) deep, the merging itself is a loop
+    fn merge_sort<F: FnMut(&T, &T) -> Ordering>(
+        head: Link<T>,
+        len: usize,
+        compare: &mut F,
+    ) -> Link<T> {
+        if len <= 1 {
+            return head;
+        }
+        let middle = len / 2;
+        let mut left_tail = head.clone();
+        for _ in 1..middle {
+            left_tail = left_tail.and_then(|node| node.borrow().next.clone());
+        }
+        let right = left_tail.and_then(|node| node.borrow_mut().next.take());
+        let left = Self::merge_sort(head, middle, compare);
+        let right = Self::merge_sort(right, len - middle, compare);
+        Self::merge(left, right, compare)
+    }
+
+    fn merge<F: FnMut(&T, &T) -> Ordering>(
+        mut left: Link<T>,
+        mut right: Link<T>,
+        compare: &mut F,
+    ) -> Link<T> {
+        let mut head: Link<T> = None;
+        let mut tail: Link<T> = None;
+        while let (Some(l), Some(r)) = (&left, &right) {
+            // Ties go to the left so equal entries keep their order, which is what makes the sort stable
+            let take_left = compare(&l.borrow().value, &r.borrow().value) != Ordering::Greater;
+            let source = if take_left { &mut left } else { &mut right };
+            let node = source.take().expect("checked above");
+            *source = node.borrow_mut().next.take();
+            match tail.take() {
+                None => head = Some(node.clone()),
+                Some(tail) => tail.borrow_mut().next = Some(node.clone()),
+            }
+            tail = Some(node);
+        }
+        // One side ran out, whatever is left on the other is already sorted and can go on the end as is
+        let rest = left.or(right);
+        match tail {
+            None => rest,
+            Some(tail) => {
+                tail.borrow_mut().next = rest;
+                head
+            }
+        }
+    }
+
+    // A cursor parked on the first entry, see CursorMut
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let current = self.head.clone();
+        CursorMut { log: self, current }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let current = self.tail.clone();
+        CursorMut { log: self, current }
+    }
+
+    // Floyd's tortoise and hare over the next links: the hare takes two steps for every one of the tortoise,
+    // so if the chain loops back on itself the hare eventually laps it and they land on the same node.
+    // A well formed log never has a cycle, this is for catching bad manual linking before an iterator spins forever
+    pub fn has_cycle(&self) -> bool {
+        let mut tortoise = self.head.clone();
+        let mut hare = self.head.clone();
+        loop {
+            hare = match hare.and_then(|node| node.borrow().next.clone()) {
+                Some(node) => node.borrow().next.clone(),
+                None => return false,
+            };
+            tortoise = tortoise.and_then(|node| node.borrow().next.clone());
+            match (&tortoise, &hare) {
+                (Some(slow), Some(fast)) if Rc::ptr_eq(slow, fast) => return true,
+                (_, None) => return false,
+                _ => {}
             }
         }
-        self.tail = Some(node);
-        self.length += 1;
     }
 
-    pub fn pop(&mut self) -> Option<String> {
-        self.head.take().map(|head| {
-            if let Some(next) = head.borrow_mut().next.take() {
-                next.borrow_mut().prev.take();
-                self.head = Some(next);
-            } else {
-                self.tail.take(); // why use take? I guess just to clean it up? probably equivalent to just setting it to None?
-            }
-            self.length -= 1;
-            println!("THIS IS THE BAD PLACE: {:?}", Rc::strong_count(&head)); // this log line was here because the unwrap panicked and I wanted to confirm it was because there additional unexpected references
-            Rc::try_unwrap(head)
-                .expect("It should just work")
-                .into_inner() // Basically "unwrapping" the RefCell
-                .value
-        })
+    // Makes right come straight after left. A missing left means right is the new head, and a missing right means
+    // left is the new tail
+    fn join(&mut self, left: Link<T>, right: Link<T>) {
+        match &right {
+            Some(right) => right.borrow_mut().prev = left.as_ref().map(Rc::downgrade),
+            None => self.tail = left.clone(),
+        }
+        match left {
+            Some(left) => left.borrow_mut().next = right,
+            None => self.head = right,
+        }
+    }
+
+    // Detaches a node that is somewhere in this log, patching up whichever of its neighbours (or head/tail) pointed at it
+    fn unlink(&mut self, node: &Rc<RefCell<Node<T>>>) {
+        let prev = node
+            .borrow_mut()
+            .prev
+            .take()
+            .and_then(|prev| prev.upgrade());
+        let next = node.borrow_mut().next.take();
+        match &next {
+            Some(next) => next.borrow_mut().prev = prev.as_ref().map(Rc::downgrade),
+            None => self.tail = prev.clone(),
+        }
+        match prev {
+            Some(prev) => prev.borrow_mut().next = next,
+            None => self.head = next,
+        }
+        self.length -= 1;
+    }
+
+    // Starts from whichever end is closer, so it's never more than half the log away
+    fn node_at(&self, index: usize) -> Link<T> {
+        if index >= self.length {
+            return None;
+        }
+        if index < self.length / 2 {
+            let mut current = self.head.clone();
+            for _ in 0..index {
+                current = current?.borrow().next.clone();
+            }
+            current
+        } else {
+            let mut current = self.tail.clone();
+            for _ in index..self.length - 1 {
+                current = current?.borrow().prev.as_ref().and_then(Weak::upgrade);
+            }
+            current
+        }
+    }
+}
+
+// Popping has to be able to clone the value out when the node is still shared, hence the extra bound
+impl<T: Clone> BetterTransactionLog<T> {
+    pub fn pop(&mut self) -> Option<T> {
+        self.head.take().map(|head| {
+            if let Some(next) = head.borrow_mut().next.take() {
+                next.borrow_mut().prev.take();
+                self.head = Some(next);
+            } else {
+                self.tail.take(); // why use take? I guess just to clean it up? probably equivalent to just setting it to None?
+            }
+            self.length -= 1;
+            Node::into_value(head)
+        })
+    }
+
+    pub fn try_pop(&mut self) -> Result<T, EmptyLogError> {
+        self.pop().ok_or(EmptyLogError)
+    }
+
+    // Up to n entries off the front in one go, fewer if the log runs out first. It's just pop in a loop,
+    // so the length stays right whenever it stops
+    pub fn pop_n(&mut self, n: usize) -> Vec<T> {
+        let mut batch = Vec::with_capacity(n.min(self.length));
+        while batch.len() < n {
+            match self.pop() {
+                Some(value) => batch.push(value),
+                None => break,
+            }
+        }
+        batch
+    }
+
+    // Copies of the first n entries, the log itself is left as it is
+    pub fn peek_n(&self, n: usize) -> Vec<T> {
+        self.iter().take(n).collect()
+    }
+
+    // Takes out `count` entries starting at `start` and links `replacement` in where they were, handing back what was
+    // taken out. A count running past the tail just takes everything from start on. The replacement gets built into a
+    // chain of its own first, so the log itself is only rewired at the two ends of the gap
+    pub fn splice(
+        &mut self,
+        start: usize,
+        count: usize,
+        replacement: Vec<T>,
+    ) -> Result<Vec<T>, OutOfBounds> {
+        if start > self.length {
+            return Err(OutOfBounds {
+                index: start,
+                length: self.length,
+            });
+        }
+        let count = count.min(self.length - start);
+        let before = match start {
+            0 => None,
+            _ => self.node_at(start - 1),
+        };
+        let mut current = match &before {
+            Some(before) => before.borrow().next.clone(),
+            None => self.head.clone(),
+        };
+        // Cut every removed node loose from its neighbours on the way, so each ends up owned only by this Vec
+        let mut removed = Vec::with_capacity(count);
+        for _ in 0..count {
+            let node = current.expect("count is within the log");
+            current = node.borrow_mut().next.take();
+            node.borrow_mut().prev.take();
+            removed.push(node);
+        }
+        let after = current;
+
+        let mut chain = BetterTransactionLog::new_empty();
+        for value in replacement {
+            chain.append(value);
+        }
+        self.adopt(&chain);
+        self.length = self.length - count + chain.length;
+        chain.length = 0;
+        match (chain.head.take(), chain.tail.take()) {
+            (Some(chain_head), Some(chain_tail)) => {
+                self.join(before, Some(chain_head));
+                self.join(Some(chain_tail), after);
+            }
+            _ => self.join(before, after),
+        }
+        self.evict_overflow();
+        Ok(removed.into_iter().map(Node::into_value).collect())
+    }
+
+    // Same as append, but hands back whatever got evicted to make room
+    pub fn append_evicting(&mut self, value: T) -> Option<T> {
+        let evicted = if self.is_full() { self.pop() } else { None };
+        self.append(value);
+        evicted
+    }
+
+    // Mirror image of pop. The new tail drops its next pointer so the old tail is only held by us
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.take().map(|tail| {
+            if let Some(prev) = tail
+                .borrow_mut()
+                .prev
+                .take()
+                .and_then(|prev| prev.upgrade())
+            {
+                prev.borrow_mut().next.take();
+                self.tail = Some(prev);
+            } else {
+                self.head.take();
+            }
+            self.length -= 1;
+            Node::into_value(tail)
+        })
+    }
+
+    // Unlinks the node at `index`, stitching its neighbours back together
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
+        }
+        if index == 0 {
+            return self.pop();
+        }
+        if index == self.length - 1 {
+            return self.pop_back();
+        }
+        // Somewhere in the middle, so both neighbours exist
+        let node = self.node_at(index)?;
+        let before = node
+            .borrow_mut()
+            .prev
+            .take()
+            .and_then(|prev| prev.upgrade())?;
+        let after = node.borrow_mut().next.take()?;
+        after.borrow_mut().prev = Some(Rc::downgrade(&before));
+        before.borrow_mut().next = Some(after);
+        self.length -= 1;
+        Some(Node::into_value(node))
+    }
+
+    // O(1) removal of the entry a handle points at. The handle only holds a weak reference,
+    // so if the entry was already removed (or popped, evicted, cleared...) this finds nothing and returns None.
+    // Same if the entry is in some other log now, split_at and append_log move nodes between logs without
+    // touching the handles, so the node's origin is what says whether it's still ours to unlink
+    pub fn remove(&mut self, handle: NodeHandle<T>) -> Option<T> {
+        let node = handle.node.upgrade()?;
+        if !self.owns(&node) || !self.is_linked(&node) {
+            return None;
+        }
+        self.unlink(&node);
+        Some(Node::into_value(node))
+    }
+
+    pub fn peek(&self) -> Option<T> {
+        self.peek_with(T::clone)
+    }
+
+    pub fn peek_back(&self) -> Option<T> {
+        self.peek_back_with(T::clone)
+    }
+
+    // Iterator-style name for the newest entry, it's just the tail so no walking
+    pub fn last(&self) -> Option<T> {
+        self.peek_back()
+    }
+
+    // find() already answers "where is this exact value", so the predicate flavours get their own names.
+    // Only the match gets cloned, everything else is just looked at
+    pub fn find_by<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<T> {
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            let node = node.borrow();
+            if predicate(&node.value) {
+                return Some(node.value.clone());
+            }
+            current = node.next.clone();
+        }
+        None
+    }
+
+    // Same as find_by but starts from the newest entry and follows the prev links
+    pub fn rfind_by<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<T> {
+        let mut current = self.tail.clone();
+        while let Some(node) = current {
+            let node = node.borrow();
+            if predicate(&node.value) {
+                return Some(node.value.clone());
+            }
+            current = node.prev.as_ref().and_then(Weak::upgrade);
+        }
+        None
+    }
+
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.node_at(index).map(|node| node.borrow().value.clone())
+    }
+
+    // Sequence numbers are identities, not positions, so this has to go looking
+    pub fn get_by_seq(&self, seq: u64) -> Option<T> {
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            if node.borrow().seq() == seq {
+                return Some(node.borrow().value.clone());
+            }
+            current = node.borrow().next.clone();
+        }
+        None
+    }
+
+    // Counts from the tail instead, so 0 is the newest entry. node_at starts from the tail for anything
+    // in the back half, which means the last few entries are only a few prev hops away
+    pub fn nth_from_end(&self, n: usize) -> Option<T> {
+        if n >= self.length {
+            return None;
+        }
+        self.get(self.length - 1 - n)
+    }
+
+    // Like nth_from_end but always follows the prev links back from the tail. Each prev is a Weak,
+    // so it has to be upgraded on the way; the node ahead of it is holding it alive so that can't fail
+    pub fn nth_back(&self, n: usize) -> Option<T> {
+        if n >= self.length {
+            return None;
+        }
+        let mut current = self.tail.clone()?;
+        for _ in 0..n {
+            let prev = current.borrow().prev.as_ref()?.upgrade()?;
+            current = prev;
+        }
+        let value = current.borrow().value.clone();
+        Some(value)
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    // These build a brand new log out of clones and leave this one alone. The new log has fresh timestamps and
+    // sequence numbers and no capacity, same as collecting into one would give you
+    pub fn map_log<U, F: FnMut(T) -> U>(&self, f: F) -> BetterTransactionLog<U> {
+        self.iter().map(f).collect()
+    }
+
+    pub fn filter_log<P: FnMut(&T) -> bool>(&self, predicate: P) -> BetterTransactionLog<T> {
+        self.iter().filter(predicate).collect()
+    }
+
+    pub fn filter_map_log<U, F: FnMut(T) -> Option<U>>(&self, f: F) -> BetterTransactionLog<U> {
+        self.iter().filter_map(f).collect()
+    }
+
+    // Walks the two logs side by side and stops at the end of the shorter one. Neither log gets touched
+    pub fn zip_values<'a, U: Clone>(
+        &'a self,
+        other: &'a BetterTransactionLog<U>,
+    ) -> impl Iterator<Item = (T, U)> + 'a {
+        self.iter().zip(other.iter())
+    }
+
+    // Same walk, but keeps going to the end of the longer one, so nothing on either side gets skipped when diffing
+    pub fn zip_longest<'a, U: Clone>(
+        &'a self,
+        other: &'a BetterTransactionLog<U>,
+    ) -> impl Iterator<Item = ZipEntry<T, U>> + 'a {
+        let mut left = self.iter();
+        let mut right = other.iter();
+        std::iter::from_fn(move || match (left.next(), right.next()) {
+            (Some(l), Some(r)) => Some(ZipEntry::Both(l, r)),
+            (Some(l), None) => Some(ZipEntry::LeftOnly(l)),
+            (None, Some(r)) => Some(ZipEntry::RightOnly(r)),
+            (None, None) => None,
+        })
+    }
+
+    // Pops rather than iterates so each value is moved out instead of cloned
+    pub fn into_vec(mut self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.length);
+        while let Some(value) = self.pop() {
+            values.push(value);
+        }
+        values
+    }
+
+    // Hands every entry over front to back and leaves the log empty but usable, even if the Drain is dropped early
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { log: self }
+    }
+
+    /// The iterator keeps the log borrowed, so its length can't go stale underneath it:
+    ///
+    /// ```compile_fail
+    /// use hands_on_data_struct_algorithms::lists::BetterTransactionLog;
+    ///
+    /// let mut log = BetterTransactionLog::from(vec![1, 2, 3]);
+    /// let iter = log.iter();
+    /// log.pop();
+    /// assert_eq!(iter.len(), 2);
+    /// ```
+    pub fn iter(&self) -> ListIteratorTracker<'_, T> {
+        ListIteratorTracker::new(self.head.clone(), self.tail.clone(), self.length)
+    }
+
+    // Same as iter() but refuses to hand out an iterator over a chain that loops, see has_cycle
+    pub fn checked_iter(&self) -> Option<ListIteratorTracker<'_, T>> {
+        if self.has_cycle() {
+            return None;
+        }
+        Some(self.iter())
+    }
+
+    // Entries stamped from `from_millis` up to but not including `to_millis`, in log order, along with their timestamps.
+    // Nothing keeps the timestamps in order from head to tail (push_front, insert, sort and friends all reorder entries,
+    // and the clock can step back), so this checks every entry rather than stopping at the first one past the range
+    pub fn range(&self, from_millis: u64, to_millis: u64) -> impl Iterator<Item = (u64, T)> {
+        let mut current = self.head.clone();
+        std::iter::from_fn(move || loop {
+            let node = current.take()?;
+            let node = node.borrow();
+            current = node.next.clone();
+            if (from_millis..to_millis).contains(&node.timestamp) {
+                return Some((node.timestamp, node.value.clone()));
+            }
+        })
+    }
+
+    // Spelled-out names for which end the walk starts from. The tracker's own constructor stays private so that
+    // Link never has to leak out of this module, these are the way in from outside
+    pub fn iter_from_head(&self) -> ListIteratorTracker<'_, T> {
+        self.iter()
+    }
+
+    pub fn iter_from_tail(&self) -> Rev<ListIteratorTracker<'_, T>> {
+        self.iter_rev()
+    }
+
+    // Borrows rather than consumes: the prev links are weak, so the log has to stay alive to keep the nodes we walk back over
+    pub fn iter_rev(&self) -> Rev<ListIteratorTracker<'_, T>> {
+        self.iter().rev()
+    }
+}
+
+// On disk each entry is its length in bytes as a little endian u64, followed by the UTF-8 bytes themselves.
+// That way a value can contain newlines or anything else without escaping. Only the values are saved, so a reloaded
+// log has fresh timestamps and sequence numbers
+impl BetterTransactionLog<String> {
+    // Writes one entry at a time straight to `w`, nothing gets collected up first. Wrap files in a BufWriter
+    pub fn save_to<W: Write>(&self, mut w: W) -> io::Result<()> {
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            let node = node.borrow();
+            w.write_all(&(node.value.len() as u64).to_le_bytes())?;
+            w.write_all(node.value.as_bytes())?;
+            current = node.next.clone();
+        }
+        w.flush()
+    }
+
+    // Reads entries back one at a time until the input runs out right at the end of one.
+    // Running out anywhere else, or bytes that aren't UTF-8, is an error instead of a panic
+    pub fn load_from<R: Read>(mut r: R) -> io::Result<BetterTransactionLog<String>> {
+        let mut log = BetterTransactionLog::new_empty();
+        while let Some(length) = Self::read_length(&mut r)? {
+            // No preallocating from the prefix, a corrupt one could ask for an absurd amount of memory
+            let mut bytes = Vec::new();
+            (&mut r).take(length).read_to_end(&mut bytes)?;
+            if bytes.len() as u64 != length {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "log entry is shorter than its length prefix",
+                ));
+            }
+            let value = String::from_utf8(bytes)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            log.append(value);
+        }
+        Ok(log)
+    }
+
+    // None for a clean end of input, read_exact can't tell that apart from a prefix that got cut off
+    fn read_length<R: Read>(r: &mut R) -> io::Result<Option<u64>> {
+        let mut prefix = [0u8; 8];
+        let mut filled = 0;
+        while filled < prefix.len() {
+            match r.read(&mut prefix[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "log ended in the middle of a length prefix",
+                    ))
+                }
+                Ok(read) => filled += read,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(Some(u64::from_le_bytes(prefix)))
+    }
+}
+
+impl<T: Hash> BetterTransactionLog<T> {
+    // append, plus a hash over the previous entry's hash and this value, so editing any entry breaks the chain from there on.
+    // The first entry chains from 0
+    pub fn append_hashed(&mut self, value: T) -> u64 {
+        let previous = self.tail_hash().unwrap_or(0);
+        let hash = chain_hash(previous, &value);
+        let seq = self.append(value);
+        let tail = self.tail.as_ref().expect("we just appended");
+        let mut tail = tail.borrow_mut();
+        tail.hash = hash;
+        tail.chained_from = previous;
+        seq
+    }
+
+    pub fn head_hash(&self) -> Option<u64> {
+        self.head.as_ref().map(|head| head.borrow().hash)
+    }
+
+    pub fn tail_hash(&self) -> Option<u64> {
+        self.tail.as_ref().map(|tail| tail.borrow().hash)
+    }
+
+    // Recomputes every hash from head to tail and gives back the index of the first entry that doesn't add up.
+    // Each node remembers what it chained from, so popping entries off the front doesn't upset it.
+    // Only makes sense for a log built with append_hashed, anything added another way has no hash and fails here
+    pub fn verify(&self) -> Result<(), usize> {
+        let mut current = self.head.clone();
+        let mut previous: Option<u64> = None;
+        let mut index = 0;
+        while let Some(node) = current {
+            let node = node.borrow();
+            let linked = previous.is_none_or(|previous| previous == node.chained_from);
+            if !linked || chain_hash(node.chained_from, &node.value) != node.hash {
+                return Err(index);
+            }
+            previous = Some(node.hash);
+            index += 1;
+            current = node.next.clone();
+        }
+        Ok(())
+    }
+}
+
+impl<T: AsRef<[u8]>> BetterTransactionLog<T> {
+    // For chasing down link bugs, like the try_unwrap in pop failing because a node is owned one time too many.
+    // The walk gives up one node past the recorded length, so a chain that loops shows up as a mismatch instead of hanging
+    pub fn debug_stats(&self) -> LogStats {
+        let mut stats = LogStats {
+            nodes: 0,
+            length: self.length,
+            value_bytes: 0,
+            min_strong: 0,
+            max_strong: 0,
+        };
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            if stats.nodes > self.length {
+                break;
+            }
+            // Minus one for the clone we're holding to walk the list
+            let strong = Rc::strong_count(&node) - 1;
+            stats.min_strong = if stats.nodes == 0 {
+                strong
+            } else {
+                stats.min_strong.min(strong)
+            };
+            stats.max_strong = stats.max_strong.max(strong);
+            stats.nodes += 1;
+            let node = node.borrow();
+            stats.value_bytes += node.value.as_ref().len();
+            current = node.next.clone();
+        }
+        stats
+    }
+}
+
+// This struct holds the state of the iterator.
+// It used to be a single cursor that next and next_back both moved, which made mixing them a bit of a seek.
+// Now each end has its own cursor and the iterator is done once they meet, like DoubleEndedIterator expects.
+// It also carries how many entries are left (the log knows its length up front) so it can be an ExactSizeIterator,
+// and running out of that count stops it too, so even a chain that loops can't keep it going forever.
+// That count is only true as long as the log doesn't change, so the 'a keeps the log borrowed while the iterator lives.
+// For walking a log that's still being popped and appended to, there's weak_iter
+pub struct ListIteratorTracker<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    remaining: usize,
+    log: PhantomData<&'a ()>,
+}
+
+impl<'a, T> ListIteratorTracker<'a, T> {
+    fn new(front: Link<T>, back: Link<T>, remaining: usize) -> ListIteratorTracker<'a, T> {
+        ListIteratorTracker {
+            front,
+            back,
+            remaining,
+            log: PhantomData,
+        }
+    }
+
+    // Called after handing out a value from either end
+    fn count_one(&mut self) {
+        self.remaining = self.remaining.saturating_sub(1);
+        if self.remaining == 0 {
+            self.front = None;
+            self.back = None;
+        }
+    }
+}
+
+impl<'a, T: Clone> ListIteratorTracker<'a, T> {
+    // Pairs each value with its position, counting from 0 like find() does
+    pub fn indexed(self) -> impl Iterator<Item = (usize, T)> + use<'a, T> {
+        (0..).zip(self)
+    }
+}
+
+impl<T: Clone> Iterator for ListIteratorTracker<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.front.take()?;
+        let met = matches!(&self.back, Some(back) if Rc::ptr_eq(back, &current));
+        if !met {
+            self.front = current.borrow().next.clone();
+        }
+        // Either the cursors just met or we fell off the end, and both mean there's nothing left for the back cursor
+        if self.front.is_none() {
+            self.back = None;
+        }
+        self.count_one();
+        let result = current.borrow().value.clone();
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for ListIteratorTracker<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        let current = self.back.take()?;
+        let met = matches!(&self.front, Some(front) if Rc::ptr_eq(front, &current));
+        if !met {
+            self.back = current.borrow().prev.as_ref().and_then(Weak::upgrade);
+        }
+        if self.back.is_none() {
+            self.front = None;
+        }
+        self.count_one();
+        let result = current.borrow().value.clone();
+        Some(result)
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for ListIteratorTracker<'_, T> {}
+
+// Once both cursors are gone they stay gone
+impl<T: Clone> FusedIterator for ListIteratorTracker<'_, T> {}
+
+// ListIteratorTracker clones every value it hands out. This one hands out the node instead,
+// and the value can be looked at through a RefCell guard for as long as the ValueRef is around.
+// Each step only bumps a reference count, no matter how big the values are
+pub struct RefIter<T> {
+    current: Link<T>,
+}
+
+pub struct ValueRef<T> {
+    node: Rc<RefCell<Node<T>>>,
+}
+
+impl<T> ValueRef<T> {
+    pub fn value(&self) -> Ref<'_, T> {
+        Ref::map(self.node.borrow(), |node| &node.value)
+    }
+}
+
+impl<T> Iterator for RefIter<T> {
+    type Item = ValueRef<T>;
+
+    fn next(&mut self) -> Option<ValueRef<T>> {
+        let node = self.current.take()?;
+        self.current = node.borrow().next.clone();
+        Some(ValueRef { node })
+    }
+}
+
+// ListIteratorTracker holds strong Rcs, and pop can't take a node back out while something else still owns it.
+// This one only keeps a Weak to the entry it'll hand out next, so the log is free to change underneath it.
+// If that entry gets popped in the meantime the iterator simply ends: a dropped node no longer knows what came after it.
+// Entries appended before it reaches the tail do show up, so it can follow a live log along
+pub struct WeakIter<T> {
+    next: Weak<RefCell<Node<T>>>,
+}
+
+impl<T: Clone> Iterator for WeakIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let node = self.next.upgrade()?;
+        let node = node.borrow();
+        self.next = node.next.as_ref().map_or_else(Weak::new, Rc::downgrade);
+        Some(node.value.clone())
+    }
+}
+
+// What a log turns into when it's iterated by value. It keeps the log itself and pops from whichever end is asked for,
+// so whatever isn't consumed is still a proper log and goes through its iterative Drop, however long it is.
+// Holding the bare chain instead would leave the default recursive drop to free the rest
+pub struct IntoIter<T> {
+    log: BetterTransactionLog<T>,
+}
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.log.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.log.len(), Some(self.log.len()))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.log.pop_back()
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for IntoIter<T> {}
+
+impl<T: Clone> FusedIterator for IntoIter<T> {}
+
+// Unlike into_iter this only borrows the log, popping entries off the front as it goes
+pub struct Drain<'a, T> {
+    log: &'a mut BetterTransactionLog<T>,
+}
+
+impl<T: Clone> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.log.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.log.len(), Some(self.log.len()))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T: Clone> FusedIterator for Drain<'_, T> {}
+
+// Whatever wasn't consumed gets thrown away, so the log is always empty once the Drain is gone
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        self.log.clear();
+    }
+}
+
+// Walks the log one node at a time and edits right where it stands, so nothing is O(n) like the index based methods.
+// It holds the log mutably, which keeps anyone else from relinking nodes out from under it.
+// Same idea as std's LinkedList cursors: past the tail (or before the head) there's a "ghost" position
+// where current is None, and moving on from the ghost wraps around to the other end
+pub struct CursorMut<'a, T> {
+    log: &'a mut BetterTransactionLog<T>,
+    current: Link<T>,
+}
+
+impl<T> CursorMut<'_, T> {
+    pub fn move_next(&mut self) {
+        self.current = match self.current.take() {
+            Some(node) => node.borrow().next.clone(),
+            None => self.log.head.clone(),
+        };
+    }
+
+    pub fn move_prev(&mut self) {
+        self.current = match self.current.take() {
+            Some(node) => node.borrow().prev.as_ref().and_then(Weak::upgrade),
+            None => self.log.tail.clone(),
+        };
+    }
+
+    // On the ghost this lands at the very back, just before the ghost
+    pub fn insert_before(&mut self, value: T) {
+        let Some(current) = &self.current else {
+            self.log.append(value);
+            return;
+        };
+        let prev = current.borrow().prev.as_ref().and_then(Weak::upgrade);
+        let node = Node::new_with(
+            value,
+            Some(current.clone()),
+            prev.as_ref().map(Rc::downgrade),
+        );
+        self.log.stamp(&node);
+        current.borrow_mut().prev = Some(Rc::downgrade(&node));
+        match prev {
+            Some(prev) => prev.borrow_mut().next = Some(node),
+            None => self.log.head = Some(node),
+        }
+        self.log.length += 1;
+        self.evict_overflow();
+    }
+
+    // And on the ghost this lands at the very front, just after it
+    pub fn insert_after(&mut self, value: T) {
+        let Some(current) = &self.current else {
+            self.log.push_front(value);
+            return;
+        };
+        let next = current.borrow_mut().next.take();
+        let node = Node::new_with(value, next.clone(), Some(Rc::downgrade(current)));
+        self.log.stamp(&node);
+        match next {
+            Some(next) => next.borrow_mut().prev = Some(Rc::downgrade(&node)),
+            None => self.log.tail = Some(node.clone()),
+        }
+        current.borrow_mut().next = Some(node);
+        self.log.length += 1;
+        self.evict_overflow();
+    }
+
+    // A bounded log may have to drop its head to fit the new entry. If the cursor was sitting on it,
+    // it carries on from the new head, same as remove_current moving on to the next entry
+    fn evict_overflow(&mut self) {
+        self.log.evict_overflow();
+        if let Some(current) = &self.current {
+            if !self.log.is_linked(current) {
+                self.current = self.log.head.clone();
+            }
+        }
+    }
+}
+
+impl<T: Clone> CursorMut<'_, T> {
+    pub fn current(&self) -> Option<T> {
+        self.current
+            .as_ref()
+            .map(|node| node.borrow().value.clone())
+    }
+
+    // Takes the entry out and moves on to the one after it (or the ghost if that was the tail)
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current.take()?;
+        self.current = node.borrow().next.clone();
+        self.log.unlink(&node);
+        Some(Node::into_value(node))
+    }
+}
+
+// Points at one entry of a log so it can be removed later in O(1), handy for an LRU cache.
+// It's weak so holding on to a handle doesn't keep a removed entry alive
+pub struct NodeHandle<T> {
+    node: Weak<RefCell<Node<T>>>,
+}
+
+impl<T> NodeHandle<T> {
+    // Handles to the neighbouring entries, so something outside the log can keep its place in it and step along
+    // one entry at a time. Both come back None at the ends, and for an entry that's no longer in a log
+    pub fn next(&self) -> Option<NodeHandle<T>> {
+        let node = self.node.upgrade()?;
+        let next = node.borrow().next.as_ref().map(Rc::downgrade)?;
+        Some(NodeHandle { node: next })
+    }
+
+    pub fn prev(&self) -> Option<NodeHandle<T>> {
+        let node = self.node.upgrade()?;
+        let prev = node.borrow().prev.clone()?;
+        Some(NodeHandle { node: prev })
+    }
+}
+
+impl<T: Clone> NodeHandle<T> {
+    // None once nothing keeps the entry alive anymore
+    pub fn value(&self) -> Option<T> {
+        let node = self.node.upgrade()?;
+        let value = node.borrow().value.clone();
+        Some(value)
+    }
+}
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        NodeHandle {
+            node: self.node.clone(),
+        }
+    }
+}
+
+impl<T: Clone> IntoIterator for BetterTransactionLog<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { log: self }
+    }
+}
+
+// So `for x in &log` works and the log sticks around afterwards
+impl<'a, T: Clone> IntoIterator for &'a BetterTransactionLog<T> {
+    type Item = T;
+    type IntoIter = ListIteratorTracker<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Default for TransactionLog<T> {
+    fn default() -> Self {
+        TransactionLog::new_empty()
+    }
+}
+
+impl<T> Default for BetterTransactionLog<T> {
+    fn default() -> Self {
+        BetterTransactionLog::new_empty()
+    }
+}
+
+impl<T> From<Vec<T>> for BetterTransactionLog<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> FromIterator<T> for BetterTransactionLog<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut log = BetterTransactionLog::new_empty();
+        log.extend(iter);
+        log
+    }
+}
+
+// append already keeps head/tail/length and the prev links straight, so just lean on it
+impl<T> Extend<T> for BetterTransactionLog<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.append(value);
+        }
+    }
+}
+
+// For production usage, a super deep linked list will cause stack overflow for the default recursive drop implementation
+// For production, probably safer to just use the some other implementation of LinkedList
+impl<T> Drop for TransactionLog<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+// Same story for the doubly linked version, clear() unlinks one node at a time
+impl<T> Drop for BetterTransactionLog<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+// Deriving Clone would only copy the head/tail pointers, so the clone shares every node with the original.
+// Now that dropping a log pops its nodes, dropping such a clone would tear the original apart, so copy the values instead
+impl<T: Clone> Clone for BetterTransactionLog<T> {
+    fn clone(&self) -> Self {
+        let mut cloned = BetterTransactionLog::new_empty();
+        cloned.capacity = self.capacity;
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            let node = node.borrow();
+            cloned.next_seq = node.seq();
+            cloned.append_at(node.value.clone(), node.timestamp); // keep the original timestamps, sequence numbers and hashes
+            if let Some(tail) = &cloned.tail {
+                tail.borrow_mut().hash = node.hash;
+                tail.borrow_mut().chained_from = node.chained_from;
+            }
+            current = node.next.clone();
+        }
+        cloned.next_seq = self.next_seq;
+        cloned
+    }
+}
+
+// Two logs are equal when they hold the same values in the same order, which nodes hold them doesn't matter.
+// Walks both chains side by side with a loop, following next only, so there's no recursion and no bouncing back along prev
+fn chains_equal<T: PartialEq>(left: &Link<T>, right: &Link<T>) -> bool {
+    let mut left = left.clone();
+    let mut right = right.clone();
+    loop {
+        match (left, right) {
+            (None, None) => return true,
+            (Some(l), Some(r)) => {
+                if l.borrow().value != r.borrow().value {
+                    return false;
+                }
+                left = l.borrow().next.clone();
+                right = r.borrow().next.clone();
+            }
+            _ => return false,
+        }
+    }
+}
+
+// Lengths are cached so that's the cheap check before walking
+impl<T: PartialEq> PartialEq for TransactionLog<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && chains_equal(&self.head, &other.head)
+    }
+}
+
+impl<T: Eq> Eq for TransactionLog<T> {}
+
+impl<T: PartialEq> PartialEq for BetterTransactionLog<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && chains_equal(&self.head, &other.head)
+    }
+}
+
+impl<T: Eq> Eq for BetterTransactionLog<T> {}
+
+// Similarly here, the default derive(Debug) will cause Stack Overflow when printing out
+impl<T: Debug> Debug for Node<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NOD")
+            .field("irreplaceable", &self.value)
+            .field("back2back", &self.prev.is_some()) // WOW! representing node without causing StackOverflow is proving to be quite the thorn!
+            .field("forwards", &self.next.is_some())
+            .finish()
+    }
+}
+
+// Anything past this many entries gets summarised as "..." so printing a huge log stays readable
+const DEBUG_ENTRY_LIMIT: usize = 100;
+
+// Walks the chain with a loop and prints just the values, since Node's Debug only shows whether the links exist
+struct DebugEntries<'a, T>(&'a Link<T>);
+
+impl<T: Debug> Debug for DebugEntries<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut list = f.debug_list();
+        let mut current = self.0.clone();
+        let mut shown = 0;
+        while let Some(node) = current {
+            if shown == DEBUG_ENTRY_LIMIT {
+                list.entry(&format_args!("..."));
+                break;
+            }
+            list.entry(&node.borrow().value);
+            current = node.borrow().next.clone();
+            shown += 1;
+        }
+        list.finish()
+    }
+}
+
+impl<T: Debug> Debug for TransactionLog<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransactionLog")
+            .field("length", &self.length)
+            .field("entries", &DebugEntries(&self.head))
+            .finish()
+    }
+}
+
+impl<T: Debug> Debug for BetterTransactionLog<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BetterTransactionLog")
+            .field("length", &self.length)
+            .field("entries", &DebugEntries(&self.head))
+            .finish()
+    }
+}
+
+// The human friendly version: a -> b -> c
+impl<T: Display> Display for BetterTransactionLog<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "(empty)");
+        }
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            let node = node.borrow();
+            write!(f, "{}", node.value)?;
+            if node.next.is_some() {
+                write!(f, " -> ")?;
+            }
+            current = node.next.clone();
+        }
+        Ok(())
+    }
+}
+
+// Snapshots go out as a plain list of values, the links are rebuilt on the way back in.
+// Walking with a loop instead of deriving keeps a deep log from blowing the stack, same reason as Drop
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for BetterTransactionLog<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.length))?;
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            seq.serialize_element(&node.borrow().value)?;
+            current = node.borrow().next.clone();
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+struct LogVisitor<T>(std::marker::PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for LogVisitor<T> {
+    type Value = BetterTransactionLog<T>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a sequence of log entries")
+    }
+
+    // append takes care of next/prev, head/tail and length, so there's nothing to patch up afterwards
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut log = BetterTransactionLog::new_empty();
+        while let Some(value) = seq.next_element()? {
+            log.append(value);
+        }
+        Ok(log)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for BetterTransactionLog<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LogVisitor(std::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod better_transaction_log_tests {
+    use super::*;
+
+    #[test]
+    fn test_appending() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert!(tl.head.is_none());
+        assert!(tl.tail.is_none());
+        assert_eq!(tl.len(), 0);
+        tl.append(String::from("Testing1"));
+        assert_eq!(tl.len(), 1);
+        assert_lone_node(&tl.head, "Testing1"); // node without a next
+        assert_lone_node(&tl.tail, "Testing1");
+        tl.append(String::from("Testing2"));
+        assert_eq!(tl.len(), 2);
+        assert!(tl.head.clone().unwrap().borrow().next.is_some()); // head has a next now
+        assert_eq!(
+            tl.tail.clone().unwrap().borrow().value,
+            String::from("Testing2")
+        );
+        tl.append(String::from("Testing3"));
+        assert_eq!(tl.len(), 3);
+        assert!(tl
+            .head
+            .clone()
+            .unwrap()
+            .borrow()
+            .next
+            .clone()
+            .unwrap()
+            .borrow()
+            .next
+            .is_some());
+        assert_eq!(
+            tl.tail.clone().unwrap().borrow().value,
+            String::from("Testing3")
+        );
+    }
+
+    #[test]
+    fn test_popping() {
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append(String::from("Testing1"));
+        tl.append(String::from("Testing2"));
+        tl.append(String::from("Testing3"));
+
+        assert_eq!(tl.pop(), Some("Testing1".to_string()));
+        assert_eq!(tl.len(), 2);
+        assert!(tl.head.clone().unwrap().borrow().next.is_some());
+        assert_eq!(
+            tl.head
+                .clone()
+                .unwrap()
+                .borrow()
+                .next
+                .clone()
+                .unwrap()
+                .borrow()
+                .value,
+            String::from("Testing3") // Testing2 is the head now, and Testing3 is its next
+        );
+        assert_eq!(tl.pop(), Some(String::from("Testing2")));
+        assert_eq!(tl.len(), 1);
+        assert_eq!(tl.pop(), Some(String::from("Testing3")));
+        assert_eq!(tl.len(), 0);
+        assert!(tl.head.is_none());
+        assert!(tl.tail.is_none());
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.push_front(String::from("Testing2"));
+        assert_eq!(tl.len(), 1);
+        assert_lone_node(&tl.head, "Testing2"); // empty list sets both ends
+        assert_lone_node(&tl.tail, "Testing2");
+        tl.append(String::from("Testing3"));
+        tl.push_front(String::from("Testing1"));
+        assert_eq!(tl.len(), 3);
+        assert_eq!(
+            tl.iter().collect::<Vec<String>>(),
+            vec!["Testing1", "Testing2", "Testing3"]
+        );
+        assert_eq!(tl.pop(), Some(String::from("Testing1")));
+        assert_eq!(tl.pop(), Some(String::from("Testing2")));
+        assert_eq!(tl.pop(), Some(String::from("Testing3")));
+        assert_eq!(tl.len(), 0);
+    }
+
+    #[test]
+    fn test_popping_with_outstanding_iterator() {
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append(String::from("Testing1"));
+        tl.append(String::from("Testing2"));
+
+        // iter() keeps the log borrowed now, so build one by hand to get a second strong reference to the head
+        let iter = ListIteratorTracker::new(tl.head.clone(), tl.tail.clone(), tl.len());
+        assert_eq!(tl.pop(), Some(String::from("Testing1")));
+        assert_eq!(tl.len(), 1);
+        assert_eq!(iter.collect::<Vec<String>>(), vec!["Testing1"]); // the popped node no longer links forward
+        assert_eq!(tl.pop(), Some(String::from("Testing2")));
+    }
+
+    #[test]
+    fn test_popping_back() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert_eq!(tl.pop_back(), None);
+        tl.append(String::from("Testing1"));
+        tl.append(String::from("Testing2"));
+        tl.append(String::from("Testing3"));
+        tl.append(String::from("Testing4"));
+
+        assert_eq!(tl.pop_back(), Some(String::from("Testing4")));
+        assert_eq!(tl.len(), 3);
+        assert!(tl.tail.clone().unwrap().borrow().next.is_none()); // new tail forgot about the popped node
+        assert_eq!(tl.pop(), Some(String::from("Testing1")));
+        assert_eq!(tl.pop_back(), Some(String::from("Testing3")));
+        assert_eq!(tl.len(), 1);
+        assert_eq!(tl.pop(), Some(String::from("Testing2")));
+        assert_eq!(tl.len(), 0);
+        assert!(tl.head.is_none());
+        assert!(tl.tail.is_none());
+        assert_eq!(tl.pop_back(), None);
+
+        tl.append(String::from("Testing5"));
+        assert_eq!(tl.pop_back(), Some(String::from("Testing5"))); // one element clears both ends
+        assert!(tl.head.is_none());
+        assert!(tl.tail.is_none());
+    }
+
+    #[test]
+    fn test_peeking() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert_eq!(tl.peek(), None);
+        assert_eq!(tl.peek_back(), None);
+        tl.append(String::from("Testing1"));
+        tl.append(String::from("Testing2"));
+        assert_eq!(tl.peek(), Some(String::from("Testing1")));
+        assert_eq!(tl.peek_back(), Some(String::from("Testing2")));
+        assert_eq!(tl.peek_with(|s| s.len()), Some(8));
+        assert_eq!(tl.peek_back_with(|s| s.ends_with('2')), Some(true));
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.pop(), Some(String::from("Testing1"))); // peeking didn't leave extra references behind
+    }
+
+    #[test]
+    fn test_peeking_leaves_log_unchanged() {
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append(String::from("Testing1"));
+        tl.append(String::from("Testing2"));
+        tl.append(String::from("Testing3"));
+        assert_eq!(tl.peek(), Some(String::from("Testing1")));
+        assert_eq!(tl.peek_back(), Some(String::from("Testing3")));
+        assert_eq!(tl.len(), 3);
+        assert!(tl.head.clone().unwrap().borrow().next.is_some());
+        assert!(tl.tail.clone().unwrap().borrow().prev.is_some());
+        assert_eq!(tl.pop(), Some(String::from("Testing1")));
+        assert_eq!(tl.peek(), Some(String::from("Testing2")));
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Tx {
+        id: u64,
+        amount: i64,
+    }
+
+    #[test]
+    fn test_struct_payload() {
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append(Tx { id: 1, amount: 100 });
+        tl.append(Tx { id: 2, amount: -40 });
+        tl.append(Tx { id: 3, amount: 25 });
+        assert_eq!(tl.iter().map(|tx| tx.amount).sum::<i64>(), 85);
+        assert_eq!(tl.pop(), Some(Tx { id: 1, amount: 100 }));
+        assert_eq!(tl.pop_back().map(|tx| tx.id), Some(3));
+        assert_eq!(
+            tl.into_iter().collect::<Vec<Tx>>(),
+            vec![Tx { id: 2, amount: -40 }]
+        );
+    }
+
+    #[test]
+    fn test_find_and_contains() {
+        let mut tl = BetterTransactionLog::<String>::new_empty();
+        assert!(!tl.contains("Testing1"));
+        assert_eq!(tl.find("Testing1"), None);
+        tl.append(String::from("Testing1"));
+        tl.append(String::from("Testing2"));
+        tl.append(String::from("Testing3"));
+        tl.append(String::from("Testing2"));
+
+        assert!(tl.contains("Testing2"));
+        assert_eq!(tl.find("Testing2"), Some(1)); // first match wins
+        assert_eq!(tl.find("Testing3"), Some(2));
+        assert!(!tl.contains("Testing4"));
+        assert_eq!(tl.find("Testing4"), None);
+        assert_eq!(tl.len(), 4);
+    }
+
+    #[test]
+    fn test_reversing() {
+        let mut tl: BetterTransactionLog<String> =
+            vec!["a", "b", "c"].into_iter().map(String::from).collect();
+        tl.reverse();
+        assert_eq!(tl.iter().collect::<Vec<String>>(), vec!["c", "b", "a"]);
+        assert_eq!(tl.iter_rev().collect::<Vec<String>>(), vec!["a", "b", "c"]);
+        assert_eq!(tl.len(), 3);
+        assert_eq!(tl.pop(), Some(String::from("c")));
+        assert_eq!(tl.pop_back(), Some(String::from("a")));
+    }
+
+    #[test]
+    fn test_popping_with_tracker_and_clone_alive() {
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append(String::from("Testing1"));
+        tl.append(String::from("Testing2"));
+
+        let tracker = ListIteratorTracker::new(tl.head.clone(), tl.tail.clone(), tl.len());
+        let cloned = tl.clone();
+        assert_eq!(tl.pop(), Some(String::from("Testing1")));
+        assert_eq!(tl.pop_back(), Some(String::from("Testing2")));
+        assert_eq!(tl.len(), 0);
+        drop(tracker);
+        assert_eq!(cloned.len(), 2);
+    }
+
+    #[test]
+    fn test_clone_is_deep() {
+        let mut tl = BetterTransactionLog::from(vec![
+            String::from("a"),
+            String::from("b"),
+            String::from("c"),
+        ]);
+        let cloned = tl.clone();
+        assert!(!Rc::ptr_eq(
+            tl.head.as_ref().unwrap(),
+            cloned.head.as_ref().unwrap()
+        ));
+        assert!(!Rc::ptr_eq(
+            tl.tail.as_ref().unwrap(),
+            cloned.tail.as_ref().unwrap()
+        ));
+
+        assert_eq!(tl.pop(), Some(String::from("a")));
+        assert_eq!(tl.pop_back(), Some(String::from("c")));
+        tl.append(String::from("d"));
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.to_vec(), vec!["b", "d"]);
+        assert_eq!(cloned.len(), 3);
+        assert_eq!(cloned.to_vec(), vec!["a", "b", "c"]);
+        assert_eq!(
+            cloned.iter_rev().collect::<Vec<String>>(),
+            vec!["c", "b", "a"]
+        );
+    }
+
+    #[test]
+    fn test_collecting_from_vec() {
+        let tl: BetterTransactionLog<String> =
+            vec!["a", "b"].into_iter().map(String::from).collect();
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.peek(), Some(String::from("a")));
+        assert_eq!(tl.peek_back(), Some(String::from("b")));
+        assert_eq!(tl.into_iter().collect::<Vec<String>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_debug_output() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert_eq!(
+            format!("{:?}", tl),
+            "BetterTransactionLog { length: 0, entries: [] }"
+        );
+        tl.extend(vec!["a", "b", "c"]);
+        assert_eq!(
+            format!("{:?}", tl),
+            r#"BetterTransactionLog { length: 3, entries: ["a", "b", "c"] }"#
+        );
+
+        let long: BetterTransactionLog<u32> = (0..100_000).collect();
+        let printed = format!("{:?}", long);
+        assert!(printed.starts_with("BetterTransactionLog { length: 100000, entries: [0, 1, 2,"));
+        assert!(printed.ends_with("98, 99, ...] }"));
+    }
+
+    #[test]
+    fn test_len_and_is_empty() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert!(tl.is_empty());
+        assert_eq!(tl.len(), 0);
+        tl.append(String::from("Testing1"));
+        tl.push_front(String::from("Testing0"));
+        assert!(!tl.is_empty());
+        assert_eq!(tl.len(), 2);
+        tl.pop_back();
+        tl.pop();
+        assert!(tl.is_empty());
+        assert_eq!(tl.len(), 0);
+    }
+
+    #[test]
+    fn test_len_tracks_appends_and_pops() {
+        let mut tl = BetterTransactionLog::new_empty();
+        for n in 0..10 {
+            tl.append(n);
+        }
+        for popped in 1..=10 {
+            if popped % 2 == 0 {
+                tl.pop();
+            } else {
+                tl.pop_back();
+            }
+            assert_eq!(tl.len(), 10 - popped);
+            assert_eq!(tl.is_empty(), tl.head.is_none());
+        }
+        assert!(tl.is_empty());
+    }
+
+    #[test]
+    fn test_display_output() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert_eq!(tl.to_string(), "(empty)");
+        tl.append("a");
+        assert_eq!(tl.to_string(), "a");
+        tl.append("b");
+        tl.append("c");
+        assert_eq!(tl.to_string(), "a -> b -> c");
+    }
+
+    #[test]
+    fn test_clearing() {
+        let mut tl: BetterTransactionLog<String> = (0..10).map(|i| i.to_string()).collect();
+        let iter = tl.iter();
+        drop(iter);
+        let mut iter = ListIteratorTracker::new(tl.head.clone(), tl.tail.clone(), tl.len());
+        iter.next();
+        tl.clear();
+        assert!(tl.is_empty());
+        assert_eq!(tl.len(), 0);
+        assert_eq!(iter.next(), Some(String::from("1"))); // the node it was parked on got detached from the rest
+        assert_eq!(iter.next(), None);
+        tl.append(String::from("Testing1"));
+        assert_eq!(tl.pop(), Some(String::from("Testing1")));
+    }
+
+    #[test]
+    fn test_to_and_into_vec() {
+        let tl: BetterTransactionLog<String> = BetterTransactionLog::new_empty();
+        assert!(tl.to_vec().is_empty());
+        assert!(tl.into_vec().is_empty());
+
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append(String::from("Testing1"));
+        tl.append(String::from("Testing2"));
+        tl.append(String::from("Testing3"));
+        assert_eq!(tl.to_vec(), vec!["Testing1", "Testing2", "Testing3"]);
+        assert_eq!(tl.len(), 3);
+        assert_eq!(tl.into_vec(), vec!["Testing1", "Testing2", "Testing3"]);
+    }
+
+    #[test]
+    fn test_find_by_and_rfind_by() {
+        let mut tl = BetterTransactionLog::<String>::new_empty();
+        assert_eq!(tl.find_by(|s| s.starts_with("DEBUG")), None);
+        assert_eq!(tl.rfind_by(|s| s.starts_with("DEBUG")), None);
+        tl.append(String::from("DEBUG: first"));
+        tl.append(String::from("INFO: middle"));
+        tl.append(String::from("DEBUG: last"));
+
+        assert_eq!(
+            tl.find_by(|s| s.starts_with("DEBUG")),
+            Some(String::from("DEBUG: first"))
+        );
+        assert_eq!(
+            tl.rfind_by(|s| s.starts_with("DEBUG")),
+            Some(String::from("DEBUG: last"))
+        );
+        assert_eq!(tl.find_by(|s| s.starts_with("WARN")), None);
+        assert_eq!(tl.rfind_by(|s| s.starts_with("WARN")), None);
+        assert!(tl.contains("INFO: middle"));
+        assert_eq!(tl.pop(), Some(String::from("DEBUG: first"))); // searching left no references behind
+    }
+
+    #[test]
+    fn test_get_and_get_ref() {
+        let tl: BetterTransactionLog<String> = (0..5).map(|i| format!("Testing{}", i)).collect();
+        assert_eq!(tl.get(0), Some(String::from("Testing0")));
+        assert_eq!(tl.get(1), Some(String::from("Testing1"))); // walked from the head
+        assert_eq!(tl.get(3), Some(String::from("Testing3"))); // walked back from the tail
+        assert_eq!(tl.get(4), Some(String::from("Testing4")));
+        assert_eq!(tl.get(5), None);
+        assert_eq!(*tl.get_ref(0).unwrap().value(), "Testing0");
+        assert_eq!(*tl.get_ref(3).unwrap().value(), "Testing3");
+        for i in 0..tl.len() {
+            let entry = tl.get_ref(i).unwrap();
+            assert_eq!(Some(entry.value().clone()), tl.get(i));
+        }
+        assert!(tl.get_ref(5).is_none());
+        assert_eq!(tl.len(), 5);
+
+        let empty: BetterTransactionLog<String> = BetterTransactionLog::new_empty();
+        assert_eq!(empty.get(0), None);
+    }
+
+    #[test]
+    fn test_get_from_either_half() {
+        let tl: BetterTransactionLog<String> = vec!["a", "b", "c", "d", "e"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(tl.get(0), Some(String::from("a")));
+        assert_eq!(tl.get(2), Some(String::from("c"))); // the middle is reached from the tail side
+        assert_eq!(tl.get(4), Some(String::from("e")));
+        assert_eq!(tl.get(5), None);
+    }
+
+    #[test]
+    fn test_get_ref_out_of_bounds() {
+        let tl: BetterTransactionLog<u32> = (0..5).collect();
+        assert!(tl.get_ref(5).is_none());
+        assert!(tl.get_ref(usize::MAX).is_none());
+        assert!(BetterTransactionLog::<u32>::new_empty()
+            .get_ref(0)
+            .is_none());
+
+        // An index that was fine a moment ago is out of range once the log shrinks past it
+        let mut tl: BetterTransactionLog<u32> = (0..5).collect();
+        assert_eq!(*tl.get_ref(4).unwrap().value(), 4);
+        tl.pop_back();
+        assert!(tl.get_ref(4).is_none());
+        tl.truncate(1);
+        assert!(tl.get_ref(1).is_none());
+        assert_eq!(*tl.get_ref(0).unwrap().value(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn test_get_ref_guards_against_writes() {
+        // Holding a borrowed value while the log rewrites it is a RefCell panic, not a dangling reference
+        let mut tl = BetterTransactionLog::from(vec![1, 2]);
+        let entry = tl.get_ref(0).unwrap();
+        let _value = entry.value();
+        tl.map_values(|value| value + 1);
+    }
+
+    #[test]
+    fn test_try_popping() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert_eq!(tl.try_pop(), Err(EmptyLogError));
+        tl.append(String::from("Testing1"));
+        assert_eq!(tl.try_pop(), Ok(String::from("Testing1")));
+        assert_eq!(tl.len(), 0);
+        let err = tl.try_pop().unwrap_err();
+        assert_eq!(err.to_string(), "cannot pop from an empty log");
+    }
+
+    #[test]
+    fn test_push_front_onto_non_empty_log() {
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append(String::from("second"));
+        tl.append(String::from("third"));
+        tl.push_front(String::from("first"));
+        assert_eq!(tl.peek(), Some(String::from("first")));
+        assert_eq!(tl.to_vec(), vec!["first", "second", "third"]);
+        assert_eq!(
+            tl.iter_rev().collect::<Vec<String>>(),
+            vec!["third", "second", "first"]
+        );
+    }
+
+    #[test]
+    fn test_patching_the_middle() {
+        let mut tl: BetterTransactionLog<String> = vec!["a", "b", "bogus", "d"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(tl.insert(2, String::from("c")), Ok(()));
+        assert_eq!(tl.remove_at(3), Some(String::from("bogus")));
+        assert_eq!(tl.len(), 4);
+        assert_eq!(tl.to_vec(), vec!["a", "b", "c", "d"]);
+        assert_eq!(
+            tl.iter_rev().collect::<Vec<String>>(),
+            vec!["d", "c", "b", "a"]
+        );
+        assert_eq!(tl.insert(0, String::from("start")), Ok(())); // same as push_front
+        assert_eq!(tl.insert(5, String::from("end")), Ok(())); // same as append
+        assert_eq!(tl.peek(), Some(String::from("start")));
+        assert_eq!(tl.peek_back(), Some(String::from("end")));
+        assert_eq!(tl.len(), 6);
+    }
+
+    #[test]
+    fn test_indexed() {
+        let tl: BetterTransactionLog<String> =
+            vec!["a", "b", "c"].into_iter().map(String::from).collect();
+        assert_eq!(
+            tl.iter().indexed().collect::<Vec<(usize, String)>>(),
+            vec![
+                (0, String::from("a")),
+                (1, String::from("b")),
+                (2, String::from("c"))
+            ]
+        );
+        assert_eq!(
+            tl.iter().indexed().find(|(_, v)| v == "c").map(|(i, _)| i),
+            tl.find("c")
+        );
+    }
+
+    #[test]
+    fn test_retaining() {
+        let mut tl: BetterTransactionLog<String> =
+            vec!["DEBUG: a", "INFO: b", "DEBUG: c", "INFO: d", "DEBUG: e"]
+                .into_iter()
+                .map(String::from)
+                .collect();
+        tl.retain(|s| !s.starts_with("DEBUG:")); // both ends and the middle go
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.to_vec(), vec!["INFO: b", "INFO: d"]);
+        assert_eq!(
+            tl.iter_rev().collect::<Vec<String>>(),
+            vec!["INFO: d", "INFO: b"]
+        );
+
+        tl.retain(|_| true); // removing nothing is a no-op
+        assert_eq!(tl.to_vec(), vec!["INFO: b", "INFO: d"]);
+        assert_eq!(tl.len(), 2);
+
+        tl.retain(|_| false);
+        assert!(tl.is_empty());
+        assert_eq!(tl.len(), 0);
+        tl.append(String::from("INFO: e")); // still a valid log
+        assert_eq!(tl.peek(), tl.peek_back());
+    }
+
+    #[test]
+    fn test_draining() {
+        let mut tl: BetterTransactionLog<String> =
+            vec!["a", "b", "c"].into_iter().map(String::from).collect();
+        let archive = tl.drain().collect::<Vec<String>>();
+        assert_eq!(archive, vec!["a", "b", "c"]);
+        assert!(tl.is_empty());
+
+        tl.extend(vec![
+            String::from("d"),
+            String::from("e"),
+            String::from("f"),
+        ]);
+        let mut drain = tl.drain();
+        assert_eq!(drain.next(), Some(String::from("d")));
+        drop(drain); // e and f go with it
+        assert!(tl.is_empty());
+        assert_eq!(tl.len(), 0);
+
+        tl.append(String::from("g"));
+        assert_eq!(tl.to_vec(), vec!["g"]);
+    }
+
+    #[test]
+    fn test_splitting() {
+        let mut tl: BetterTransactionLog<u32> = (0..5).collect();
+        let back = tl.split_at(2);
+        assert_eq!(tl.to_vec(), vec![0, 1]);
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.peek_back(), Some(1));
+        assert_eq!(back.to_vec(), vec![2, 3, 4]);
+        assert_eq!(back.len(), 3);
+        assert_eq!(back.iter_rev().collect::<Vec<u32>>(), vec![4, 3, 2]); // new head has no prev
+
+        let mut tl: BetterTransactionLog<u32> = (0..5).collect();
+        let everything = tl.split_at(0);
+        assert!(tl.is_empty());
+        assert_eq!(everything.to_vec(), vec![0, 1, 2, 3, 4]);
+
+        let mut tl: BetterTransactionLog<u32> = (0..5).collect();
+        let nothing = tl.split_at(5);
+        assert!(nothing.is_empty());
+        assert_eq!(nothing.len(), 0);
+        assert_eq!(tl.len(), 5);
+    }
+
+    #[test]
+    fn test_split_halves_are_independent() {
+        let mut archive: BetterTransactionLog<u32> = (0..6).collect();
+        let mut live = archive.split_at(3);
+        assert!(archive.tail.clone().unwrap().borrow().next.is_none());
+        assert!(live.head.clone().unwrap().borrow().prev.is_none());
+
+        archive.append(100);
+        live.append(200);
+        live.push_front(300);
+        assert_eq!(archive.to_vec(), vec![0, 1, 2, 100]);
+        assert_eq!(live.to_vec(), vec![300, 3, 4, 5, 200]);
+        assert_eq!(archive.len(), 4);
+        assert_eq!(live.len(), 5);
+        assert_eq!(
+            live.iter_rev().collect::<Vec<u32>>(),
+            vec![200, 5, 4, 3, 300]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index 6 is out of bounds for a log of length 5")]
+    fn test_splitting_out_of_bounds() {
+        let mut tl: BetterTransactionLog<u32> = (0..5).collect();
+        tl.split_at(6);
+    }
+
+    #[test]
+    fn test_reversing_edge_cases() {
+        let mut empty: BetterTransactionLog<u32> = BetterTransactionLog::new_empty();
+        empty.reverse();
+        assert!(empty.is_empty());
+
+        let mut single: BetterTransactionLog<u32> = (0..1).collect();
+        single.reverse();
+        assert_eq!(single.to_vec(), vec![0]);
+        assert_eq!(single.peek(), single.peek_back());
+
+        let original: Vec<u32> = (0..100).collect();
+        let mut tl: BetterTransactionLog<u32> = original.clone().into_iter().collect();
+        tl.reverse();
+        assert_eq!(
+            tl.to_vec(),
+            original.iter().rev().cloned().collect::<Vec<u32>>()
+        );
+        tl.reverse();
+        assert_eq!(tl.to_vec(), original);
+        assert_eq!(
+            tl.iter_rev().collect::<Vec<u32>>(),
+            tl.to_vec().into_iter().rev().collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn test_appending_logs() {
+        let mut tl: BetterTransactionLog<String> =
+            vec!["a", "b"].into_iter().map(String::from).collect();
+        let other: BetterTransactionLog<String> =
+            vec!["c", "d"].into_iter().map(String::from).collect();
+        tl.append_log(other);
+        assert_eq!(tl.len(), 4);
+        assert_eq!(tl.to_vec(), vec!["a", "b", "c", "d"]);
+        assert_eq!(
+            tl.iter_rev().collect::<Vec<String>>(),
+            vec!["d", "c", "b", "a"]
+        );
+
+        let mut empty = BetterTransactionLog::new_empty();
+        empty.append_log(tl); // adopts the other log wholesale
+        assert_eq!(empty.len(), 4);
+        assert_eq!(empty.peek(), Some(String::from("a")));
+        assert_eq!(empty.peek_back(), Some(String::from("d")));
+
+        // Only the two ends get relinked. Any other node being touched would trip over the guard we're holding
+        let mut tl: BetterTransactionLog<u32> = (0..3).collect();
+        let other: BetterTransactionLog<u32> = (3..1_000_000).collect();
+        let held = other.get_ref(500_000).unwrap();
+        let guard = held.value();
+        tl.append_log(other);
+        assert_eq!(*guard, 500_003);
+        drop(guard);
+        assert_eq!(tl.len(), 1_000_000);
+        assert_eq!(tl.get_by_seq(3 + 500_000), Some(500_003));
+    }
+
+    #[test]
+    fn test_retaining_long_values() {
+        let mut tl: BetterTransactionLog<String> = vec!["abcd", "ab", "abcde", "a", "abc"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        tl.retain(|s| s.len() > 3);
+        assert_eq!(tl.to_vec(), vec!["abcd", "abcde"]);
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.peek_back(), Some(String::from("abcde")));
+
+        tl.retain(|_| false);
+        assert!(tl.is_empty());
+        assert!(tl.head.is_none());
+        assert!(tl.tail.is_none());
+    }
+
+    #[test]
+    fn test_appending_logs_empty_combinations() {
+        let log = |values: &[u32]| {
+            values
+                .iter()
+                .cloned()
+                .collect::<BetterTransactionLog<u32>>()
+        };
+
+        let mut both_empty = log(&[]);
+        both_empty.append_log(log(&[]));
+        assert!(both_empty.is_empty());
+
+        let mut self_empty = log(&[]);
+        self_empty.append_log(log(&[1, 2]));
+        assert_eq!(self_empty.to_vec(), vec![1, 2]);
+
+        let mut other_empty = log(&[1, 2]);
+        other_empty.append_log(log(&[]));
+        assert_eq!(other_empty.to_vec(), vec![1, 2]);
+        assert_eq!(other_empty.len(), 2);
+
+        let mut merged = log(&[1, 2]);
+        merged.append_log(log(&[3, 4]));
+        assert_eq!(merged.pop(), Some(1));
+        assert_eq!(merged.pop_back(), Some(4));
+        assert_eq!(merged.pop_back(), Some(3)); // walks back across the join
+        assert_eq!(merged.pop_back(), Some(2));
+        assert!(merged.is_empty());
+    }
+
+    // Counts how often it gets cloned, which for a String payload would be one allocation each
+    struct CloneCounter {
+        clones: Rc<std::cell::Cell<usize>>,
+    }
+
+    impl Clone for CloneCounter {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            CloneCounter {
+                clones: self.clones.clone(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_iter_refs_does_not_clone() {
+        let mut tl: BetterTransactionLog<String> =
+            vec!["a", "b", "c"].into_iter().map(String::from).collect();
+        let lengths = tl
+            .iter_refs()
+            .map(|entry| entry.value().len())
+            .sum::<usize>();
+        assert_eq!(lengths, 3);
+        assert_eq!(
+            tl.iter_refs()
+                .map(|entry| entry.value().clone())
+                .collect::<Vec<String>>(),
+            tl.to_vec()
+        );
+        assert_eq!(tl.pop(), Some(String::from("a"))); // no references left behind
+
+        let clones = Rc::new(std::cell::Cell::new(0));
+        let mut tl = BetterTransactionLog::new_empty();
+        for _ in 0..1_000 {
+            tl.append(CloneCounter {
+                clones: clones.clone(),
+            });
+        }
+        tl.iter().for_each(drop);
+        assert_eq!(clones.get(), 1_000); // one clone per entry
+        tl.iter_refs().for_each(|entry| drop(entry.value()));
+        assert_eq!(clones.get(), 1_000); // and none at all here
+    }
+
+    #[test]
+    fn test_sorting() {
+        // A cheap linear congruential shuffle so the test doesn't need a rand dependency
+        let mut seed: u64 = 42;
+        let mut values: Vec<u64> = (0..10_000).collect();
+        for i in (1..values.len()).rev() {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            values.swap(i, (seed >> 33) as usize % (i + 1));
+        }
+        let mut tl: BetterTransactionLog<u64> = values.into_iter().collect();
+        tl.sort();
+        assert_eq!(tl.len(), 10_000);
+        assert_eq!(tl.to_vec(), (0..10_000).collect::<Vec<u64>>());
+        assert_eq!(
+            tl.iter_rev().collect::<Vec<u64>>(),
+            (0..10_000).rev().collect::<Vec<u64>>()
+        );
+        assert_eq!(tl.peek_back(), Some(9_999));
+
+        let mut empty: BetterTransactionLog<u64> = BetterTransactionLog::new_empty();
+        empty.sort();
+        assert!(empty.is_empty());
+        let mut single: BetterTransactionLog<u64> = (7..8).collect();
+        single.sort();
+        assert_eq!(single.to_vec(), vec![7]);
+        assert_eq!(single.peek_back(), Some(7));
+    }
+
+    #[test]
+    fn test_sort_small_string_log() {
+        let mut tl: BetterTransactionLog<String> =
+            vec!["c", "a", "b"].into_iter().map(String::from).collect();
+        tl.sort();
+        assert_eq!(tl.to_vec(), vec!["a", "b", "c"]);
+        assert_eq!(tl.iter_rev().collect::<Vec<String>>(), vec!["c", "b", "a"]);
+        assert_eq!(tl.peek(), Some(String::from("a")));
+        assert_eq!(tl.peek_back(), Some(String::from("c")));
+        assert_eq!(tl.len(), 3);
+    }
+
+    #[test]
+    fn test_sort_by_is_stable() {
+        let mut tl: BetterTransactionLog<String> = vec!["b1", "a1", "b2", "a2", "c1", "a3"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        tl.sort_by(|x, y| x[..1].cmp(&y[..1])); // only compare the letter
+        assert_eq!(tl.to_vec(), vec!["a1", "a2", "a3", "b1", "b2", "c1"]);
+        tl.sort_by(|x, y| y.cmp(x));
+        assert_eq!(tl.to_vec(), vec!["c1", "b2", "b1", "a3", "a2", "a1"]);
+        assert_eq!(
+            tl.iter_rev().collect::<Vec<String>>(),
+            vec!["a1", "a2", "a3", "b1", "b2", "c1"]
+        );
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut tl: BetterTransactionLog<u32> = vec![7, 7, 7, 7].into_iter().collect();
+        tl.dedup();
+        assert_eq!(tl.to_vec(), vec![7]);
+        assert_eq!(tl.len(), 1);
+        assert_eq!(tl.peek(), tl.peek_back());
+
+        let mut tl: BetterTransactionLog<u32> = vec![1, 2, 1, 2].into_iter().collect();
+        tl.dedup();
+        assert_eq!(tl.to_vec(), vec![1, 2, 1, 2]);
+
+        let mut tl: BetterTransactionLog<u32> = vec![1, 2, 3, 3, 3].into_iter().collect();
+        tl.dedup(); // duplicates at the tail
+        assert_eq!(tl.to_vec(), vec![1, 2, 3]);
+        assert_eq!(tl.peek_back(), Some(3));
+        assert_eq!(tl.iter_rev().collect::<Vec<u32>>(), vec![3, 2, 1]);
+
+        let mut empty: BetterTransactionLog<u32> = BetterTransactionLog::new_empty();
+        empty.dedup();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_dedup_runs_at_both_ends() {
+        let mut tl: BetterTransactionLog<String> = ["a", "a", "b", "a", "a"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        tl.dedup();
+        assert_eq!(tl.to_vec(), vec!["a", "b", "a"]);
+        assert_eq!(tl.len(), 3);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec!["a", "b", "a"]); // prev links patched too
+        let tail = tl.tail.clone().unwrap();
+        assert!(tail.borrow().next.is_none());
+        let head = tl.head.clone().unwrap();
+        assert!(head.borrow().prev.is_none());
+    }
+
+    #[test]
+    fn test_dedup_by_key() {
+        let mut tl: BetterTransactionLog<String> = vec!["a1", "a2", "b1", "a3", "a4"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        tl.dedup_by_key(|s| s.chars().next());
+        assert_eq!(tl.to_vec(), vec!["a1", "b1", "a3"]); // the first of each run survives
+        assert_eq!(tl.len(), 3);
+    }
+
+    #[test]
+    fn test_from_empty_vec() {
+        let mut tl = BetterTransactionLog::from(Vec::<String>::new());
+        assert!(tl.head.is_none());
+        assert!(tl.tail.is_none());
+        assert!(tl.is_empty());
+        tl.append(String::from("still works")); // a proper empty log, not a half-built one
+        assert_eq!(tl.peek(), tl.peek_back());
+        assert_eq!(tl.to_vec(), vec!["still works"]);
+    }
+
+    #[test]
+    fn test_vec_round_trips() {
+        for values in [vec![], vec!["a"], vec!["a", "b", "c"]] {
+            let values: Vec<String> = values.into_iter().map(String::from).collect();
+            let mut tl = BetterTransactionLog::from(values.clone());
+            assert_eq!(tl.len(), values.len());
+            assert_eq!(tl.to_vec(), values);
+            assert_eq!(tl.len(), values.len()); // to_vec left it intact and poppable
+            if let Some(first) = values.first() {
+                assert_eq!(tl.pop().as_ref(), Some(first));
+                tl.push_front(first.clone());
+            }
+            assert_eq!(tl.into_vec(), values);
+        }
+    }
+
+    #[test]
+    fn test_equality() {
+        let a = BetterTransactionLog::from(vec!["x", "y", "z"]);
+        let b: BetterTransactionLog<&str> = ["x", "y", "z"].into_iter().collect();
+        assert_eq!(a, b); // different nodes, same values
+        assert_eq!(a, a.clone());
+
+        let reordered = BetterTransactionLog::from(vec!["z", "y", "x"]);
+        assert_ne!(a, reordered);
+
+        let shorter = BetterTransactionLog::from(vec!["x", "y"]);
+        assert_ne!(a, shorter);
+        assert_ne!(shorter, a);
+
+        let empty = BetterTransactionLog::<&str>::new_empty();
+        assert_eq!(empty, BetterTransactionLog::new_empty());
+        assert_ne!(empty, shorter);
+    }
+
+    #[test]
+    fn test_equality_on_long_logs() {
+        let a: BetterTransactionLog<u8> = std::iter::repeat_n(7, 100_000).collect();
+        let b = a.clone();
+        assert_eq!(a, b); // loops instead of recursing, so no overflow here
+
+        let mut c = b.clone();
+        c.pop_back();
+        c.append(8);
+        assert_ne!(a, c); // only the very last value differs
+    }
+
+    // Nodes don't compare anymore, so check the bits the tests care about: the value and that nothing follows it
+    pub(super) fn assert_lone_node(link: &Link<String>, expected: &str) {
+        let node = link.clone().expect("expected a node");
+        assert_eq!(node.borrow().value, expected);
+        assert!(node.borrow().next.is_none());
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        assert!(!BetterTransactionLog::<u8>::new_empty().has_cycle());
+        assert!(!BetterTransactionLog::from(vec![1]).has_cycle());
+        let tl = BetterTransactionLog::from(vec![1, 2, 3, 4]);
+        assert!(!tl.has_cycle());
+        assert!(tl.checked_iter().is_some());
+
+        // Hand-link a 3 node loop: 1 -> 2 -> 3 -> 1
+        let mut tl = BetterTransactionLog::from(vec![1, 2, 3]);
+        let tail = tl.tail.clone().unwrap();
+        tail.borrow_mut().next = tl.head.clone();
+        assert!(tl.has_cycle());
+        assert!(tl.checked_iter().is_none());
+
+        // Undo the damage so the log can be freed normally
+        tail.borrow_mut().next = None;
+        assert!(!tl.has_cycle());
+        assert_eq!(
+            tl.checked_iter().unwrap().collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        // A loop that skips the tail entirely: 1 -> 2 -> 1, with 3 unreachable going forwards
+        tl.head
+            .clone()
+            .unwrap()
+            .borrow()
+            .next
+            .clone()
+            .unwrap()
+            .borrow_mut()
+            .next = tl.head.clone();
+        assert!(tl.has_cycle());
+        tl.clear(); // clear takes every next as it goes, which also breaks the loop
+    }
+
+    #[test]
+    fn test_iterators_know_their_length() {
+        let tl = BetterTransactionLog::from(vec![1, 2, 3, 4, 5]);
+        let mut iter = tl.iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+        iter.next();
+        assert_eq!(iter.len(), 4);
+        iter.next_back();
+        assert_eq!(iter.len(), 3); // both ends count
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.len(), 0);
+
+        let mut owned = tl.clone().into_iter();
+        assert_eq!(owned.len(), 5);
+        owned.next_back();
+        assert_eq!(owned.len(), 4);
+
+        // ExactSizeIterator + DoubleEndedIterator is what enumerate().rev() needs
+        let backwards: Vec<(usize, i32)> = tl.iter().enumerate().rev().collect();
+        assert_eq!(backwards.first(), Some(&(4, 5)));
+        assert_eq!(backwards.last(), Some(&(0, 1)));
+
+        let mut drained = tl.clone();
+        let mut drain = drained.drain();
+        assert_eq!(drain.len(), 5);
+        drain.next();
+        assert_eq!(drain.len(), 4);
+    }
+
+    #[test]
+    fn test_iterator_stops_on_a_loop() {
+        let mut tl = BetterTransactionLog::from(vec![1, 2, 3]);
+        // 1 -> 2 -> 1 -> ..., the tail is never reached going forwards
+        tl.head
+            .clone()
+            .unwrap()
+            .borrow()
+            .next
+            .clone()
+            .unwrap()
+            .borrow_mut()
+            .next = tl.head.clone();
+        // What comes out of a looped chain is meaningless, all that matters is that it ends
+        assert!(tl.iter().take(tl.len() + 1).count() <= tl.len());
+        assert!(tl.has_cycle());
+        assert!(tl.checked_iter().is_none());
+        tl.clear();
+    }
+
+    #[test]
+    fn test_nth_from_end() {
+        let tl = BetterTransactionLog::from(vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(tl.nth_from_end(0), tl.peek_back());
+        assert_eq!(tl.nth_from_end(0), Some("e"));
+        assert_eq!(tl.nth_from_end(2), Some("c"));
+        assert_eq!(tl.nth_from_end(4), Some("a"));
+        assert_eq!(tl.nth_from_end(5), None);
+        assert_eq!(tl.nth_from_end(usize::MAX), None);
+        assert_eq!(
+            BetterTransactionLog::<&str>::new_empty().nth_from_end(0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cursor_editing() {
+        let mut tl = BetterTransactionLog::from(vec!["a", "b", "c", "d", "e"]);
+        let mut cursor = tl.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some("c"));
+        assert_eq!(cursor.remove_current(), Some("c"));
+        assert_eq!(cursor.current(), Some("d")); // moved on to what came next
+        assert_eq!(cursor.remove_current(), Some("d"));
+        cursor.insert_before("x");
+        assert_eq!(cursor.current(), Some("e")); // inserting doesn't move the cursor
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some("x"));
+
+        assert_eq!(tl.len(), 4);
+        assert_eq!(tl.to_vec(), vec!["a", "b", "x", "e"]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec!["e", "x", "b", "a"]);
+    }
+
+    #[test]
+    fn test_cursor_at_the_ends() {
+        let mut tl = BetterTransactionLog::from(vec![2, 3]);
+        let mut cursor = tl.cursor_front_mut();
+        cursor.insert_before(1); // new head
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(1));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None); // parked on the ghost
+        assert_eq!(cursor.remove_current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(3)); // and wrapped around to the tail
+        cursor.insert_after(4); // new tail
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.insert_before(5); // from the ghost that's the back
+        cursor.insert_after(0); // and this is the front
+        assert_eq!(tl.to_vec(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1, 0]);
+        assert_eq!(tl.len(), 6);
+
+        let mut cursor = tl.cursor_back_mut();
+        assert_eq!(cursor.remove_current(), Some(5));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(tl.peek_back(), Some(4));
+
+        let mut tl = BetterTransactionLog::from(vec!["only"]);
+        let mut cursor = tl.cursor_front_mut();
+        assert_eq!(cursor.remove_current(), Some("only"));
+        assert!(tl.head.is_none());
+        assert!(tl.tail.is_none());
+        assert!(tl.is_empty());
+
+        let mut empty = BetterTransactionLog::new_empty();
+        let mut cursor = empty.cursor_back_mut();
+        assert_eq!(cursor.current(), None);
+        cursor.insert_after("first");
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some("first"));
+        assert_eq!(empty.len(), 1);
+    }
+
+    #[test]
+    fn test_bounded_log_evicts_oldest() {
+        let mut tl = BetterTransactionLog::with_capacity(3);
+        assert_eq!(tl.capacity(), Some(3));
+        for i in 0..6 {
+            tl.append(i);
+            assert!(tl.len() <= 3);
+        }
+        assert_eq!(tl.len(), 3);
+        assert_eq!(tl.to_vec(), vec![3, 4, 5]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec![5, 4, 3]);
+        assert_eq!(tl.peek(), Some(3));
+
+        assert_eq!(tl.append_evicting(6), Some(3));
+        assert_eq!(tl.to_vec(), vec![4, 5, 6]);
+        tl.pop();
+        assert_eq!(tl.append_evicting(7), None); // there was room
+        assert_eq!(tl.to_vec(), vec![5, 6, 7]);
+
+        let copy = tl.clone();
+        assert_eq!(copy.capacity(), Some(3));
+
+        let mut unbounded = BetterTransactionLog::new_empty();
+        assert_eq!(unbounded.capacity(), None);
+        for i in 0..10 {
+            assert_eq!(unbounded.append_evicting(i), None);
+        }
+        assert_eq!(unbounded.len(), 10);
+    }
+
+    #[test]
+    fn test_capacity_holds_on_every_path() {
+        // Adding at the front of a full log drops the new entry itself, it's the one at the head
+        let mut tl = BetterTransactionLog::with_capacity(2);
+        for i in 0..5 {
+            tl.push_front(i);
+        }
+        assert_eq!(tl.to_vec(), vec![1, 0]);
+        tl.insert(1, 9).unwrap();
+        assert_eq!(tl.to_vec(), vec![9, 0]);
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec![0, 9]);
+
+        tl.append_log(BetterTransactionLog::from(vec![7, 8]));
+        assert_eq!(tl.to_vec(), vec![7, 8]);
+        assert_eq!(tl.splice(1, 0, vec![1, 2, 3]), Ok(Vec::new()));
+        assert_eq!(tl.to_vec(), vec![3, 8]);
+
+        let mut tl = BetterTransactionLog::with_capacity(3);
+        tl.append_all([1, 5]);
+        let mut tl = tl.merge_sorted(BetterTransactionLog::from(vec![2, 3, 4]));
+        assert_eq!(tl.to_vec(), vec![3, 4, 5]);
+        assert_eq!(tl.capacity(), Some(3));
+
+        // A cursor parked on the evicted head moves on to the new one
+        let mut cursor = tl.cursor_front_mut();
+        cursor.insert_after(6);
+        assert_eq!(cursor.current(), Some(6));
+        cursor.insert_before(7); // lands at the head, so it's the one that goes
+        assert_eq!(cursor.current(), Some(6));
+        assert_eq!(tl.to_vec(), vec![6, 4, 5]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec![5, 4, 6]);
+        assert_eq!(tl.len(), 3);
+    }
+
+    #[test]
+    fn test_capacity_three_keeps_last_three() {
+        let mut tl = BetterTransactionLog::with_capacity(3);
+        for value in ["a", "b", "c", "d", "e"] {
+            tl.append(value);
+        }
+        assert_eq!(tl.len(), 3);
+        assert_eq!(tl.to_vec(), vec!["c", "d", "e"]);
+        let head = tl.head.clone().unwrap();
+        assert_eq!(head.borrow().value, "c");
+        assert!(head.borrow().prev.is_none()); // the evicted node isn't hanging off the front
+        let tail = tl.tail.clone().unwrap();
+        assert_eq!(tail.borrow().value, "e");
+        assert!(tail.borrow().next.is_none());
+
+        let mut unbounded = BetterTransactionLog::new_empty();
+        for i in 0..1000 {
+            unbounded.append(i);
+        }
+        assert_eq!(unbounded.len(), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity of at least 1")]
+    fn test_zero_capacity_is_rejected() {
+        BetterTransactionLog::<u8>::with_capacity(0);
+    }
+
+    #[test]
+    fn test_removing_by_handle() {
+        let mut tl = BetterTransactionLog::new_empty();
+        let first = tl.append_with_handle("a");
+        let middle = tl.append_with_handle("b");
+        let last = tl.append_with_handle("c");
+
+        assert_eq!(tl.remove(middle.clone()), Some("b"));
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.to_vec(), vec!["a", "c"]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec!["c", "a"]);
+        assert_eq!(tl.remove(middle), None); // already gone
+
+        assert_eq!(tl.remove(last.clone()), Some("c"));
+        assert_eq!(tl.peek_back(), Some("a"));
+        assert_eq!(tl.remove(first.clone()), Some("a"));
+        assert!(tl.is_empty());
+        assert!(tl.head.is_none());
+        assert!(tl.tail.is_none());
+        assert_eq!(tl.remove(first), None);
+        assert_eq!(tl.remove(last), None);
+
+        // Popped entries are gone too, even while an iterator keeps the node itself alive
+        let handle = tl.append_with_handle("d");
+        tl.append("e");
+        let mut iter = ListIteratorTracker::new(tl.head.clone(), tl.tail.clone(), tl.len());
+        assert_eq!(tl.pop(), Some("d"));
+        assert_eq!(tl.remove(handle), None);
+        assert_eq!(tl.len(), 1);
+        iter.next();
+    }
+
+    #[test]
+    fn test_removing_a_handle_that_moved_logs() {
+        let mut tl = BetterTransactionLog::from(vec![0, 1, 2, 3]);
+        let handle = tl.append_with_handle(4);
+        let mut other = tl.split_at(2);
+        assert_eq!(tl.remove(handle.clone()), None); // it's other's entry now
+        assert_eq!(tl.to_vec(), vec![0, 1]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec![1, 0]);
+        assert_eq!(other.len(), 3);
+        assert_eq!(other.remove(handle), Some(4));
+        assert_eq!(other.to_vec(), vec![2, 3]);
+        assert_eq!(other.iter_rev().collect::<Vec<_>>(), vec![3, 2]);
+
+        // The front half moving out, when it's the shorter one
+        let mut tl = BetterTransactionLog::new_empty();
+        let front = tl.append_with_handle(0);
+        let back = tl.append_with_handle(1);
+        tl.append_all(2..6);
+        let mut rest = tl.split_at(1);
+        assert_eq!(rest.remove(front.clone()), None);
+        assert_eq!(tl.remove(back.clone()), None);
+        assert_eq!(rest.remove(back), Some(1));
+        assert_eq!(tl.remove(front), Some(0));
+        assert!(tl.is_empty());
+        assert_eq!(rest.to_vec(), vec![2, 3, 4, 5]);
+
+        // Joined logs take the handles along, and a log that never had the entry can't touch it
+        let mut tl = BetterTransactionLog::from(vec!["a"]);
+        let mut joined = BetterTransactionLog::from(vec!["b"]);
+        let c = joined.append_with_handle("c");
+        let mut stranger = BetterTransactionLog::from(vec!["x"]);
+        tl.append_log(joined);
+        assert_eq!(stranger.remove(c.clone()), None);
+        assert_eq!(stranger.to_vec(), vec!["x"]);
+        assert_eq!(tl.remove(c), Some("c"));
+        assert_eq!(tl.to_vec(), vec!["a", "b"]);
+        assert_eq!(tl.peek_back(), Some("b"));
+    }
+
+    #[test]
+    fn test_range_by_timestamp() {
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append_at("a", 100);
+        tl.append_at("b", 200);
+        tl.append_at("c", 200);
+        tl.append_at("d", 300);
+        tl.append_at("e", 400);
+
+        // from is inclusive, to is exclusive
+        assert_eq!(
+            tl.range(200, 400).collect::<Vec<_>>(),
+            vec![(200, "b"), (200, "c"), (300, "d")]
+        );
+        assert_eq!(tl.range(201, 300).count(), 0);
+        assert_eq!(tl.range(0, u64::MAX).count(), 5);
+        assert_eq!(tl.range(400, 401).collect::<Vec<_>>(), vec![(400, "e")]);
+        assert_eq!(tl.range(300, 300).count(), 0);
+        assert_eq!(
+            BetterTransactionLog::<u8>::new_empty().range(0, 10).count(),
+            0
+        );
+
+        let copy = tl.clone();
+        assert_eq!(copy.range(100, 101).collect::<Vec<_>>(), vec![(100, "a")]); // clones keep their timestamps
+
+        // Out of order stamps don't cut the walk short
+        let mut shuffled = BetterTransactionLog::new_empty();
+        shuffled.append_at("old", 100);
+        shuffled.append_at("mid", 200);
+        shuffled.push_front("now");
+        assert_eq!(
+            shuffled.range(0, 300).collect::<Vec<_>>(),
+            vec![(100, "old"), (200, "mid")]
+        );
+        shuffled.append_at("older", 50); // as if the clock stepped back
+        shuffled.append_at("late", 250);
+        assert_eq!(
+            shuffled
+                .range(0, 210)
+                .map(|(_, value)| value)
+                .collect::<Vec<_>>(),
+            vec!["old", "mid", "older"]
+        );
+
+        // Plain append stamps with the clock
+        let before = now_millis();
+        tl.append("f");
+        let (stamp, value) = tl.range(before, u64::MAX).last().unwrap();
+        assert_eq!(value, "f");
+        assert!(stamp >= before);
+    }
+
+    #[test]
+    fn test_sequence_numbers() {
+        let mut tl = BetterTransactionLog::new_empty();
+        let seqs: Vec<u64> = (0..6).map(|i| tl.append(i * 10)).collect();
+        assert_eq!(seqs, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(tl.get_by_seq(4), Some(40));
+        assert_eq!(tl.get_by_seq(6), None);
+
+        tl.pop_until(3); // the consumer got through entries 0, 1 and 2
+        assert_eq!(tl.to_vec(), vec![30, 40, 50]);
+        assert_eq!(tl.get_by_seq(2), None);
+        assert_eq!(tl.get_by_seq(3), Some(30));
+        assert_eq!(tl.get_by_seq(5), Some(50));
+
+        // Still counting up after the pops, they're not positions
+        assert_eq!(tl.append(60), 6);
+        tl.pop();
+        assert_eq!(tl.append(70), 7);
+        assert_eq!(tl.get_by_seq(7), Some(70));
+
+        let copy = tl.clone();
+        assert_eq!(copy.get_by_seq(4), Some(40)); // clones keep the numbers
+
+        tl.pop_until(3); // nothing below 3 left
+        assert_eq!(tl.len(), 4);
+        tl.pop_until(100);
+        assert!(tl.is_empty());
+        assert!(tl.tail.is_none());
+        assert_eq!(tl.append(80), 8);
+    }
+
+    #[test]
+    fn test_sequence_numbers_out_of_position() {
+        // A push_front entry is numbered after everything already in the log, yet it still sits in front of b
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append("a");
+        let b = tl.append("b");
+        tl.push_front("z");
+        tl.pop_until(b);
+        assert_eq!(tl.to_vec(), vec!["b"]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec!["b"]);
+
+        // Without the target in the log only the lower numbers go, wherever they are
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append("a");
+        let b = tl.append("b");
+        tl.push_front("z");
+        tl.append("c");
+        tl.retain(|value| *value != "b");
+        tl.pop_until(b);
+        assert_eq!(tl.to_vec(), vec!["z", "c"]);
+        tl.pop_until(100);
+        assert!(tl.is_empty() && tl.head.is_none() && tl.tail.is_none());
+    }
+
+    #[test]
+    fn test_sequence_numbers_stay_unique_across_logs() {
+        fn seqs<T>(tl: &BetterTransactionLog<T>) -> Vec<u64> {
+            let mut seqs = Vec::new();
+            let mut current = tl.head.clone();
+            while let Some(node) = current {
+                seqs.push(node.borrow().seq());
+                current = node.borrow().next.clone();
+            }
+            seqs
+        }
+
+        // The split off half is numbered afresh, and joining it back lifts its numbers past ours
+        let mut tl = BetterTransactionLog::from(vec!["a", "b"]);
+        let mut back = tl.split_at(1);
+        assert_eq!(seqs(&back), vec![0]);
+        let c = tl.append("c");
+        let d = back.append("d");
+        assert_eq!((c, d), (2, 1));
+        tl.append_log(back);
+        assert_eq!(tl.to_vec(), vec!["a", "c", "b", "d"]);
+        assert_eq!(seqs(&tl), vec![0, 2, 3, 4]);
+        assert_eq!(tl.get_by_seq(c), Some("c"));
+        assert_eq!(tl.append("e"), 5);
+
+        // Same for a merge: other's entries are numbered after self's, in their own order
+        let mut left = BetterTransactionLog::new_empty();
+        left.append(1);
+        left.append(4);
+        let right = BetterTransactionLog::from(vec![2, 3]);
+        let mut merged = left.merge_sorted(right);
+        assert_eq!(merged.to_vec(), vec![1, 2, 3, 4]);
+        assert_eq!(seqs(&merged), vec![0, 2, 3, 1]);
+        assert_eq!(merged.append(5), 4);
+        merged.pop_until(2);
+        assert_eq!(merged.to_vec(), vec![2, 3, 5]);
+
+        // Logs that were joined into other logs before they got joined to us
+        let mut inner = BetterTransactionLog::from(vec!["x"]);
+        inner.append_log(BetterTransactionLog::from(vec!["y", "z"]));
+        let mut middle = BetterTransactionLog::from(vec!["m"]);
+        middle.append_log(inner);
+        let mut outer = BetterTransactionLog::from(vec!["o", "p"]);
+        outer.append_log(middle);
+        assert_eq!(outer.to_vec(), vec!["o", "p", "m", "x", "y", "z"]);
+        assert_eq!(seqs(&outer), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(outer.get_by_seq(4), Some("y"));
+        assert_eq!(outer.append("q"), 6);
+
+        // Splitting and rejoining over and over only adds the split off half's count each time
+        let mut tl: BetterTransactionLog<u32> = (0..10).collect();
+        for round in 0..100 {
+            let mut back = tl.split_at(tl.len() / 2);
+            tl.append(round);
+            back.append(round);
+            tl.append_log(back);
+            tl.truncate(10);
+        }
+        let mut numbers = seqs(&tl);
+        numbers.sort();
+        numbers.dedup();
+        assert_eq!(numbers.len(), 10);
+        assert!(tl.next_seq < 1_000);
+    }
+
+    #[test]
+    fn test_save_and_load_through_memory() {
+        let tl: BetterTransactionLog<String> = ["plain", "two\nlines", "", "naïve ☕", "\r\n\n"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let mut bytes = Vec::new();
+        tl.save_to(&mut bytes).unwrap();
+        let loaded = BetterTransactionLog::load_from(bytes.as_slice()).unwrap();
+        assert_eq!(loaded, tl);
+        assert_eq!(loaded.len(), 5);
+        assert_eq!(
+            loaded.iter_rev().collect::<Vec<_>>(),
+            vec!["\r\n\n", "naïve ☕", "", "two\nlines", "plain"]
+        );
+
+        let mut bytes = Vec::new();
+        BetterTransactionLog::<String>::new_empty()
+            .save_to(&mut bytes)
+            .unwrap();
+        assert!(bytes.is_empty());
+        let loaded = BetterTransactionLog::load_from(bytes.as_slice()).unwrap();
+        assert!(loaded.is_empty());
+        assert!(loaded.head.is_none());
+    }
+
+    #[test]
+    fn test_loading_corrupt_input() {
+        let tl = BetterTransactionLog::from(vec![String::from("hello"), String::from("world")]);
+        let mut bytes = Vec::new();
+        tl.save_to(&mut bytes).unwrap();
+
+        // Cut off inside the last value, and inside a length prefix
+        for cut in [bytes.len() - 1, 8 + 5 + 3] {
+            let err = BetterTransactionLog::load_from(&bytes[..cut]).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        }
+
+        // A prefix promising far more than is there
+        let mut huge = u64::MAX.to_le_bytes().to_vec();
+        huge.extend_from_slice(b"tiny");
+        let err = BetterTransactionLog::load_from(huge.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        // Not UTF-8
+        let mut invalid = 2u64.to_le_bytes().to_vec();
+        invalid.extend_from_slice(&[0xff, 0xfe]);
+        let err = BetterTransactionLog::load_from(invalid.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_save_and_load_through_a_file() {
+        let path = std::env::temp_dir().join(format!(
+            "transaction-log-{}-save-and-load.bin",
+            std::process::id()
+        ));
+        let tl: BetterTransactionLog<String> =
+            (0..1000).map(|i| format!("entry {}\n", i)).collect();
+        tl.save_to(io::BufWriter::new(std::fs::File::create(&path).unwrap()))
+            .unwrap();
+        let loaded = BetterTransactionLog::load_from(io::BufReader::new(
+            std::fs::File::open(&path).unwrap(),
+        ));
+        std::fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded, tl);
+        assert_eq!(loaded.peek_back(), Some(String::from("entry 999\n")));
+    }
+
+    #[test]
+    fn test_replay() {
+        let tl = BetterTransactionLog::from(vec![
+            String::from("+10"),
+            String::from("-3"),
+            String::from("oops"),
+            String::from("+5"),
+        ]);
+        let balance = std::cell::Cell::new(0);
+        let apply = |entry: &String| -> Result<(), std::num::ParseIntError> {
+            balance.set(balance.get() + entry.parse::<i32>()?);
+            Ok(())
+        };
+        let (failed_at, _) = tl.replay(apply).unwrap_err();
+        assert_eq!(failed_at, 2);
+        assert_eq!(balance.get(), 7); // the first two made it in
+        assert_eq!(tl.len(), 4); // and the log is untouched
+
+        // Skip the bad entry and carry on from there
+        assert_eq!(tl.replay_from(failed_at + 1, apply), Ok(1));
+        assert_eq!(balance.get(), 12);
+
+        // Resuming right at the failure fails at the same absolute index
+        assert_eq!(
+            tl.replay_from(failed_at, |_| Err("still broken")),
+            Err((2, "still broken"))
+        );
+
+        let clean = BetterTransactionLog::from(vec![1, 2, 3]);
+        let mut seen = Vec::new();
+        assert_eq!(
+            clean.replay(|value| -> Result<(), ()> {
+                seen.push(*value);
+                Ok(())
+            }),
+            Ok(3)
+        );
+        assert_eq!(seen, vec![1, 2, 3]);
+        assert_eq!(clean.replay_from(3, |_| Err(())), Ok(0)); // nothing left to replay
+    }
+
+    #[test]
+    fn test_hash_chain() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert_eq!(tl.head_hash(), None);
+        assert_eq!(tl.verify(), Ok(()));
+        for entry in ["open", "deposit 10", "withdraw 3", "close"] {
+            tl.append_hashed(String::from(entry));
+        }
+        assert_eq!(tl.verify(), Ok(()));
+        assert_ne!(tl.head_hash(), tl.tail_hash());
+        assert_eq!(tl.head_hash(), Some(chain_hash(0, &String::from("open"))));
+        assert_eq!(tl.clone().verify(), Ok(())); // clones keep the hashes
+
+        // Same values in the same order always give the same hashes
+        let mut again = BetterTransactionLog::new_empty();
+        for entry in ["open", "deposit 10", "withdraw 3", "close"] {
+            again.append_hashed(String::from(entry));
+        }
+        assert_eq!(again.tail_hash(), tl.tail_hash());
+
+        // Quietly edit the third entry
+        let third = tl.node_at(2).unwrap();
+        third.borrow_mut().value = String::from("withdraw 300");
+        assert_eq!(tl.verify(), Err(2));
+        third.borrow_mut().value = String::from("withdraw 3");
+        assert_eq!(tl.verify(), Ok(()));
+
+        // Rewriting the second entry and its hash still gets caught by the entry after it
+        let second = tl.node_at(1).unwrap();
+        let forged = chain_hash(second.borrow().chained_from, &String::from("deposit 1000"));
+        second.borrow_mut().value = String::from("deposit 1000");
+        second.borrow_mut().hash = forged;
+        assert_eq!(tl.verify(), Err(2));
+
+        // The front can be popped off without breaking anything
+        let mut tl = again;
+        tl.pop();
+        assert_eq!(tl.verify(), Ok(()));
+        tl.append("unhashed".to_string());
+        assert_eq!(tl.verify(), Err(3));
+    }
+
+    #[test]
+    fn test_rfind() {
+        let tl: BetterTransactionLog<String> = ["pay rent", "buy milk", "pay rent", "buy bread"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(tl.find("pay rent"), Some(0));
+        assert_eq!(tl.rfind("pay rent"), Some(2)); // the latest one, counted from the head
+        assert_eq!(tl.rfind("buy milk"), tl.find("buy milk")); // only one of those
+        assert_eq!(tl.rfind("buy bread"), Some(3));
+        assert_eq!(tl.rfind("sell car"), None);
+        assert_eq!(BetterTransactionLog::<String>::new_empty().rfind("x"), None);
+    }
+
+    #[test]
+    fn test_append_all_matches_appending_one_by_one() {
+        let values: Vec<String> = (0..100_000).map(|i| format!("entry {}", i)).collect();
+        let mut batched = BetterTransactionLog::from(vec![String::from("already here")]);
+        let mut looped = batched.clone();
+        batched.append_all(values.clone());
+        for value in values {
+            looped.append(value);
+        }
+        assert_eq!(batched, looped);
+        assert_eq!(batched.len(), 100_001);
+        assert_eq!(batched.peek_back(), looped.peek_back());
+        assert_eq!(batched.nth_from_end(1), Some(String::from("entry 99998"))); // walks prev
+        assert_eq!(batched.append(String::from("next")), 100_001); // numbering carried on
+        assert_eq!(
+            batched.get_by_seq(100_000),
+            Some(String::from("entry 99999"))
+        );
+
+        let mut empty = BetterTransactionLog::new_empty();
+        empty.append_all(vec![1, 2, 3]);
+        assert_eq!(empty.to_vec(), vec![1, 2, 3]);
+        assert_eq!(empty.iter_rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+        empty.append_all(Vec::new());
+        assert_eq!(empty.len(), 3);
+
+        let mut bounded = BetterTransactionLog::with_capacity(3);
+        bounded.append(0);
+        bounded.append_all(1..6);
+        assert_eq!(bounded.to_vec(), vec![3, 4, 5]);
+        assert_eq!(bounded.len(), 3);
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let left: BetterTransactionLog<String> = ["a", "c"].into_iter().map(String::from).collect();
+        let right: BetterTransactionLog<String> =
+            ["b", "d"].into_iter().map(String::from).collect();
+        let merged = left.merge_sorted(right);
+        assert_eq!(merged.to_vec(), vec!["a", "b", "c", "d"]);
+        assert_eq!(
+            merged.iter_rev().collect::<Vec<_>>(),
+            vec!["d", "c", "b", "a"]
+        );
+        assert_eq!(merged.len(), 4);
+
+        let merged = BetterTransactionLog::from(vec![1, 5, 9])
+            .merge_sorted(BetterTransactionLog::new_empty());
+        assert_eq!(merged.to_vec(), vec![1, 5, 9]);
+        let merged =
+            BetterTransactionLog::new_empty().merge_sorted(BetterTransactionLog::from(vec![2, 3]));
+        assert_eq!(merged.to_vec(), vec![2, 3]);
+        assert_eq!(merged.peek_back(), Some(3));
+        let merged =
+            BetterTransactionLog::<u8>::new_empty().merge_sorted(BetterTransactionLog::new_empty());
+        assert!(merged.is_empty());
+        assert!(merged.tail.is_none());
+
+        // One side running out early, with duplicates across the two
+        let merged = BetterTransactionLog::from(vec![1, 2, 2, 10, 11])
+            .merge_sorted(BetterTransactionLog::from(vec![2, 3]));
+        assert_eq!(merged.to_vec(), vec![1, 2, 2, 2, 3, 10, 11]);
+        assert_eq!(merged.nth_from_end(0), Some(11));
+        assert_eq!(merged.len(), 7);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut tl = BetterTransactionLog::from(vec!["a", "b", "c", "d"]);
+        assert_eq!(tl.swap(0, 3), Ok(()));
+        assert_eq!(tl.to_vec(), vec!["d", "b", "c", "a"]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec!["a", "c", "b", "d"]);
+        assert_eq!(tl.swap(2, 1), Ok(())); // neighbours, and in either order
+        assert_eq!(tl.to_vec(), vec!["d", "c", "b", "a"]);
+        assert_eq!(tl.swap(1, 1), Ok(()));
+        assert_eq!(tl.to_vec(), vec!["d", "c", "b", "a"]);
+        assert_eq!(
+            tl.swap(1, 4),
+            Err(OutOfBounds {
+                index: 4,
+                length: 4
+            })
+        );
+        assert_eq!(
+            tl.swap(9, 0),
+            Err(OutOfBounds {
+                index: 9,
+                length: 4
+            })
+        );
+        assert_eq!(tl.to_vec(), vec!["d", "c", "b", "a"]); // left alone on error
+
+        // Head and tail when they're also neighbours
+        let mut pair = BetterTransactionLog::from(vec![1, 2]);
+        pair.swap(1, 0).unwrap();
+        assert_eq!(pair.to_vec(), vec![2, 1]);
+        assert_eq!(pair.iter_rev().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!((pair.peek(), pair.peek_back()), (Some(2), Some(1)));
+
+        // Neighbours in the middle, then far apart in the middle
+        let mut tl = BetterTransactionLog::from(vec![0, 1, 2, 3, 4, 5]);
+        tl.swap(2, 3).unwrap();
+        assert_eq!(tl.to_vec(), vec![0, 1, 3, 2, 4, 5]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec![5, 4, 2, 3, 1, 0]);
+        tl.swap(4, 1).unwrap();
+        assert_eq!(tl.to_vec(), vec![0, 4, 3, 2, 1, 5]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec![5, 1, 2, 3, 4, 0]);
+        tl.swap(0, 1).unwrap(); // the head and its neighbour
+        tl.swap(4, 5).unwrap(); // the tail and its neighbour
+        assert_eq!(tl.to_vec(), vec![4, 0, 3, 2, 5, 1]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec![1, 5, 2, 3, 0, 4]);
+        assert_eq!(tl.len(), 6);
+
+        // The nodes move rather than their contents, so handles follow their entry
+        let mut tl = BetterTransactionLog::new_empty();
+        let a = tl.append_with_handle("a");
+        tl.append("b");
+        tl.append("c");
+        tl.swap(0, 2).unwrap();
+        assert_eq!(tl.remove(a), Some("a"));
+        assert_eq!(tl.to_vec(), vec!["c", "b"]);
+
+        // Sequence numbers travel with their entries
+        let mut tl = BetterTransactionLog::new_empty();
+        let x = tl.append("x");
+        tl.append("y");
+        tl.swap(0, 1).unwrap();
+        assert_eq!(tl.get_by_seq(x), Some("x"));
+    }
+
+    #[test]
+    fn test_rotate_left() {
+        let mut tl = BetterTransactionLog::from(vec![1, 2, 3, 4, 5]);
+        tl.rotate_left(2);
+        assert_eq!(tl.to_vec(), vec![3, 4, 5, 1, 2]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec![2, 1, 5, 4, 3]);
+        assert_eq!(tl.peek(), Some(3));
+        assert_eq!(tl.peek_back(), Some(2));
+
+        tl.rotate_left(0);
+        tl.rotate_left(5);
+        assert_eq!(tl.to_vec(), vec![3, 4, 5, 1, 2]); // no-ops
+        tl.rotate_left(4);
+        assert_eq!(tl.to_vec(), vec![2, 3, 4, 5, 1]);
+        tl.rotate_left(11); // same as 1
+        assert_eq!(tl.to_vec(), vec![3, 4, 5, 1, 2]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec![2, 1, 5, 4, 3]);
+        assert_eq!(tl.len(), 5);
+
+        let mut empty = BetterTransactionLog::<u8>::new_empty();
+        empty.rotate_left(3);
+        assert!(empty.is_empty());
+        let mut single = BetterTransactionLog::from(vec![1]);
+        single.rotate_left(1);
+        assert_eq!(single.to_vec(), vec![1]);
+    }
+
+    #[test]
+    fn test_default_last_and_nth_back() {
+        let mut tl: BetterTransactionLog<String> = BetterTransactionLog::default();
+        assert_eq!(tl, BetterTransactionLog::new_empty());
+        assert_eq!(tl.capacity(), None);
+        assert_eq!(tl.last(), None);
+        assert_eq!(tl.nth_back(0), None);
+
+        tl.append_all(["a", "b", "c", "d"].map(String::from));
+        assert_eq!(tl.last(), Some(String::from("d")));
+        assert_eq!(tl.nth_back(0), tl.last()); // 0 is the tail
+        assert_eq!(tl.nth_back(1), Some(String::from("c")));
+        assert_eq!(tl.nth_back(3), Some(String::from("a"))); // len - 1 is the head
+        assert_eq!(tl.nth_back(3), tl.peek());
+        assert_eq!(tl.nth_back(4), None);
+        assert_eq!(tl.nth_back(usize::MAX), None);
+        for n in 0..tl.len() {
+            assert_eq!(tl.nth_back(n), tl.nth_from_end(n));
+        }
+
+        // The prev links still hold up after the head has been popped off
+        tl.pop();
+        assert_eq!(tl.nth_back(2), Some(String::from("b")));
+        assert_eq!(tl.nth_back(3), None);
+    }
+
+    #[test]
+    fn test_rotate_left_matches_vecdeque() {
+        // Round robin style: VecDeque::rotate_left is the reference, with n wrapped since it won't take n > len
+        let mut tl = BetterTransactionLog::from((0..7).collect::<Vec<u32>>());
+        let mut expected: std::collections::VecDeque<u32> = (0..7).collect();
+        for n in [1, 3, 6, 7, 0, 9, 14, 20] {
+            tl.rotate_left(n);
+            expected.rotate_left(n % expected.len());
+            assert_eq!(tl.to_vec(), Vec::from(expected.clone()), "rotating by {n}");
+            assert_eq!(
+                tl.iter_rev().collect::<Vec<u32>>(),
+                expected.iter().rev().copied().collect::<Vec<u32>>()
+            );
+        }
+
+        // A full lap one step at a time lands back where it started
+        let mut tl = BetterTransactionLog::from(vec!["a", "b", "c"]);
+        for _ in 0..3 {
+            tl.rotate_left(1);
+        }
+        assert_eq!(tl.to_vec(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_weak_iter_survives_pops() {
+        let mut tl = BetterTransactionLog::from(vec!["a", "b", "c", "d"]);
+        let mut ahead = tl.weak_iter();
+        assert_eq!(ahead.next(), Some("a"));
+        assert_eq!(ahead.next(), Some("b"));
+        let mut behind = tl.weak_iter();
+        assert_eq!(behind.next(), Some("a"));
+
+        // Neither iterator stops these from going through
+        assert_eq!(tl.pop(), Some("a"));
+        assert_eq!(tl.pop(), Some("b"));
+        assert_eq!(tl.len(), 2);
+
+        // This one was already past the popped entries, and sees what gets appended later on
+        assert_eq!(ahead.next(), Some("c"));
+        tl.append("e");
+        assert_eq!(ahead.collect::<Vec<_>>(), vec!["d", "e"]);
+        // This one was waiting on "b", which is gone now
+        assert_eq!(behind.next(), None);
+        assert_eq!(behind.next(), None);
+
+        assert_eq!(
+            BetterTransactionLog::<u8>::new_empty().weak_iter().next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_count() {
+        let tl: BetterTransactionLog<String> = ["BEGIN", "INSERT", "INSERT", "COMMIT", "INSERT"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(tl.count("INSERT"), 3);
+        assert_eq!(tl.count("BEGIN"), 1);
+        assert_eq!(tl.count("ROLLBACK"), 0);
+        assert_eq!(tl.count(&String::from("COMMIT")), 1);
+        assert_eq!(
+            BetterTransactionLog::<String>::new_empty().count("INSERT"),
+            0
+        );
+    }
+
+    #[test]
+    fn test_iter_from_either_end() {
+        // Only the public methods, no reaching into head or tail
+        let tl = BetterTransactionLog::from(vec![1, 2, 3, 4]);
+        assert_eq!(tl.iter_from_head().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(tl.iter_from_tail().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+        assert_eq!(tl.iter_from_tail().len(), 4);
+
+        // Still double ended, so the two cursors meet in the middle from either starting point
+        let mut from_tail = tl.iter_from_tail();
+        assert_eq!(from_tail.next(), Some(4));
+        assert_eq!(from_tail.next_back(), Some(1));
+        assert_eq!(from_tail.collect::<Vec<_>>(), vec![3, 2]);
+
+        let empty = BetterTransactionLog::<u8>::new_empty();
+        assert_eq!(empty.iter_from_head().next(), None);
+        assert_eq!(empty.iter_from_tail().next(), None);
+    }
+
+    #[test]
+    fn test_debug_stats() {
+        let mut tl = BetterTransactionLog::from(vec![
+            String::from("one"),
+            String::from("two"),
+            String::from("three"),
+        ]);
+        let healthy = LogStats {
+            nodes: 3,
+            length: 3,
+            value_bytes: 11,
+            min_strong: 1,
+            max_strong: 2, // the tail, owned by its prev and by `tail`
+        };
+        assert_eq!(tl.debug_stats(), healthy);
+
+        // An extra owner on a node is exactly what makes pop's try_unwrap fail
+        let extra = tl.tail.clone();
+        assert_eq!(tl.debug_stats().max_strong, 3);
+        drop(extra);
+        assert_eq!(tl.debug_stats(), healthy);
+
+        // A length that doesn't match the chain
+        tl.length += 1;
+        let stats = tl.debug_stats();
+        assert_eq!((stats.nodes, stats.length), (3, 4));
+        tl.length -= 1;
+
+        let empty = BetterTransactionLog::<&str>::new_empty().debug_stats();
+        assert_eq!(empty.nodes, 0);
+        assert_eq!(empty.max_strong, 0);
+    }
+
+    #[test]
+    fn test_pop_n_and_peek_n() {
+        let mut tl: BetterTransactionLog<String> = (0..250).map(|i| i.to_string()).collect();
+        assert_eq!(tl.peek_n(0), Vec::<String>::new());
+        assert_eq!(tl.pop_n(0), Vec::<String>::new());
+        assert_eq!(tl.peek_n(3), vec!["0", "1", "2"]);
+        assert_eq!(tl.len(), 250); // peeking leaves everything in place
+
+        // Batches of 100 until it's empty, the last one comes up short
+        let mut batches = Vec::new();
+        while !tl.is_empty() {
+            let batch = tl.pop_n(100);
+            batches.push((batch.first().cloned(), batch.len(), tl.len()));
+        }
+        assert_eq!(
+            batches,
+            vec![
+                (Some(String::from("0")), 100, 150),
+                (Some(String::from("100")), 100, 50),
+                (Some(String::from("200")), 50, 0),
+            ]
+        );
+        assert_eq!(tl.pop_n(100), Vec::<String>::new());
+        assert_eq!(tl.peek_n(100), Vec::<String>::new());
+        assert!(tl.head.is_none() && tl.tail.is_none());
+
+        // Asking for more than there is
+        tl.append_all(["x", "y"].map(String::from));
+        assert_eq!(tl.peek_n(10), vec!["x", "y"]);
+        assert_eq!(tl.pop_n(10), vec!["x", "y"]);
+        assert!(tl.is_empty());
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let tl: BetterTransactionLog<String> = ["b", "d", "d", "d", "f"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(tl.binary_search("b"), Ok(0));
+        assert_eq!(tl.binary_search("f"), Ok(4));
+        assert!(matches!(tl.binary_search("d"), Ok(1..=3))); // any of the duplicates will do
+        assert_eq!(tl.binary_search("a"), Err(0));
+        assert_eq!(tl.binary_search("c"), Err(1));
+        assert_eq!(tl.binary_search("e"), Err(4));
+        assert_eq!(tl.binary_search("g"), Err(5));
+
+        // Agrees with the slice version everywhere
+        let values = tl.to_vec();
+        for probe in ["a", "b", "c", "d", "e", "f", "g"] {
+            let expected = values.binary_search_by(|value| value.as_str().cmp(probe));
+            assert_eq!(tl.binary_search(probe).is_ok(), expected.is_ok());
+            if expected.is_err() {
+                assert_eq!(tl.binary_search(probe), expected);
+            }
+        }
+        assert_eq!(
+            BetterTransactionLog::<String>::new_empty().binary_search("a"),
+            Err(0)
+        );
+    }
+
+    #[test]
+    fn test_map_values() {
+        let mut tl: BetterTransactionLog<String> = ["begin", "insert", "commit"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let (head, tail) = (tl.head.clone().unwrap(), tl.tail.clone().unwrap());
+        tl.map_values(|value| value.to_uppercase());
+        assert_eq!(tl.to_vec(), vec!["BEGIN", "INSERT", "COMMIT"]);
+        assert_eq!(
+            tl.iter_rev().collect::<Vec<String>>(),
+            vec!["COMMIT", "INSERT", "BEGIN"]
+        );
+        assert_eq!(tl.len(), 3);
+        // Same nodes at either end, only their values changed
+        assert!(Rc::ptr_eq(&head, tl.head.as_ref().unwrap()));
+        assert!(Rc::ptr_eq(&tail, tl.tail.as_ref().unwrap()));
+        drop((head, tail));
+        assert_eq!(tl.pop(), Some(String::from("BEGIN")));
+
+        let mut empty = BetterTransactionLog::<String>::new_empty();
+        empty.map_values(|_| unreachable!());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_splice() {
+        // Walks it from both ends and checks they agree with each other and with len
+        fn both_ways(tl: &BetterTransactionLog<u32>) -> Vec<u32> {
+            let forwards = tl.to_vec();
+            let mut backwards = tl.iter_rev().collect::<Vec<u32>>();
+            backwards.reverse();
+            assert_eq!(forwards, backwards);
+            assert_eq!(forwards.len(), tl.len());
+            forwards
+        }
+        let mut tl = BetterTransactionLog::from(vec![1, 2, 3, 4, 5]);
+
+        // In the middle
+        assert_eq!(tl.splice(1, 2, vec![20, 30, 35]), Ok(vec![2, 3]));
+        assert_eq!(both_ways(&tl), vec![1, 20, 30, 35, 4, 5]);
+
+        // At the head
+        assert_eq!(tl.splice(0, 1, vec![10]), Ok(vec![1]));
+        assert_eq!(both_ways(&tl), vec![10, 20, 30, 35, 4, 5]);
+
+        // Through the tail, with a count that runs past it
+        assert_eq!(tl.splice(4, 10, vec![40, 50]), Ok(vec![4, 5]));
+        assert_eq!(both_ways(&tl), vec![10, 20, 30, 35, 40, 50]);
+        assert_eq!(tl.peek_back(), Some(50));
+
+        // Nothing to put back is a plain delete
+        assert_eq!(tl.splice(3, 1, Vec::new()), Ok(vec![35]));
+        assert_eq!(both_ways(&tl), vec![10, 20, 30, 40, 50]);
+
+        // Nothing taken out is a plain insert, including right at either end
+        assert_eq!(tl.splice(2, 0, vec![25]), Ok(Vec::new()));
+        assert_eq!(tl.splice(0, 0, vec![5]), Ok(Vec::new()));
+        assert_eq!(tl.splice(tl.len(), 0, vec![60]), Ok(Vec::new()));
+        assert_eq!(both_ways(&tl), vec![5, 10, 20, 25, 30, 40, 50, 60]);
+
+        // Everything, and then into the empty log
+        assert_eq!(tl.splice(0, 8, Vec::new()).unwrap().len(), 8);
+        assert!(tl.is_empty() && tl.head.is_none() && tl.tail.is_none());
+        assert_eq!(tl.splice(0, 3, vec![7, 8]), Ok(Vec::new()));
+        assert_eq!(both_ways(&tl), vec![7, 8]);
+
+        assert_eq!(
+            tl.splice(3, 0, vec![9]),
+            Err(OutOfBounds {
+                index: 3,
+                length: 2
+            })
+        );
+        assert_eq!(tl.to_vec(), vec![7, 8]);
+    }
+
+    #[test]
+    fn test_map_filter_and_filter_map_logs() {
+        let values: Vec<String> = ["1", "two", "3", "", "5"].map(String::from).to_vec();
+        let tl: BetterTransactionLog<String> = values.iter().cloned().collect();
+
+        let mapped = tl.map_log(|value| format!("<{value}>"));
+        let expected: Vec<String> = values.iter().map(|value| format!("<{value}>")).collect();
+        assert_eq!(mapped.to_vec(), expected);
+        assert_eq!(mapped.len(), expected.len());
+
+        let filtered = tl.filter_log(|value| !value.is_empty());
+        let expected: Vec<String> = values
+            .iter()
+            .filter(|value| !value.is_empty())
+            .cloned()
+            .collect();
+        assert_eq!(filtered.to_vec(), expected);
+        assert_eq!(filtered.len(), 4);
+
+        let parsed = tl.filter_map_log(|value| value.parse::<u32>().ok());
+        let expected: Vec<u32> = values
+            .iter()
+            .filter_map(|value| value.parse().ok())
+            .collect();
+        assert_eq!(parsed.to_vec(), expected);
+        assert_eq!(parsed.iter_rev().collect::<Vec<u32>>(), vec![5, 3, 1]);
+        assert_eq!(parsed.len(), 3);
+
+        // The source is left as it was and still pops cleanly, the new logs don't share its nodes
+        assert_eq!(tl.to_vec(), values);
+        let mut tl = tl;
+        while tl.pop().is_some() {}
+        assert_eq!(filtered.peek(), Some(String::from("1")));
+        assert!(tl.filter_log(|_| true).is_empty());
+    }
+
+    #[test]
+    fn test_zip_values() {
+        let a = BetterTransactionLog::from(vec!["x", "y", "z"]);
+        let b = BetterTransactionLog::from(vec![1, 2, 3]);
+        assert_eq!(
+            a.zip_values(&b).collect::<Vec<_>>(),
+            vec![("x", 1), ("y", 2), ("z", 3)]
+        );
+
+        // Stops at the shorter one, whichever side that's on
+        let short = BetterTransactionLog::from(vec![9]);
+        assert_eq!(a.zip_values(&short).collect::<Vec<_>>(), vec![("x", 9)]);
+        assert_eq!(short.zip_values(&a).collect::<Vec<_>>(), vec![(9, "x")]);
+
+        let empty = BetterTransactionLog::<&str>::new_empty();
+        assert_eq!(a.zip_values(&empty).count(), 0);
+        assert_eq!(empty.zip_values(&a).count(), 0);
+        assert_eq!(a.len(), 3); // neither side was consumed
+    }
+
+    #[test]
+    fn test_zip_longest() {
+        let a = BetterTransactionLog::from(vec!["x", "y", "z"]);
+        let b = BetterTransactionLog::from(vec!["x", "q"]);
+        assert_eq!(
+            a.zip_longest(&b).collect::<Vec<_>>(),
+            vec![
+                ZipEntry::Both("x", "x"),
+                ZipEntry::Both("y", "q"),
+                ZipEntry::LeftOnly("z"),
+            ]
+        );
+        assert_eq!(b.zip_longest(&a).last(), Some(ZipEntry::RightOnly("z")));
+        // Equal lengths never have a one sided entry
+        assert!(a
+            .zip_longest(&a.clone())
+            .all(|entry| matches!(entry, ZipEntry::Both(l, r) if l == r)));
+
+        let empty = BetterTransactionLog::<&str>::new_empty();
+        assert_eq!(
+            empty.zip_longest(&b).collect::<Vec<_>>(),
+            vec![ZipEntry::RightOnly("x"), ZipEntry::RightOnly("q")]
+        );
+        assert_eq!(empty.zip_longest(&empty).next(), None);
+        assert_eq!((a.len(), b.len()), (3, 2));
+    }
+
+    #[test]
+    fn test_stepping_along_handles() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert!(tl.front_handle().is_none());
+        tl.append("a");
+        let b = tl.append_with_handle("b");
+        tl.append("c");
+
+        let front = tl.front_handle().unwrap();
+        assert_eq!(front.value(), Some("a"));
+        assert!(front.prev().is_none());
+        assert_eq!(front.next().and_then(|h| h.value()), Some("b"));
+        assert_eq!(b.prev().and_then(|h| h.value()), Some("a"));
+        let c = b.next().unwrap();
+        assert_eq!(c.value(), Some("c"));
+        assert!(c.next().is_none());
+
+        // A removed entry is cut off from both sides, and once it's dropped there's nothing left to read
+        assert_eq!(tl.remove(b.clone()), Some("b"));
+        assert!(b.next().is_none() && b.prev().is_none());
+        assert_eq!(b.value(), None);
+        assert_eq!(front.next().and_then(|h| h.value()), Some("c"));
+    }
+
+    // Keeps a tally of how many payloads are alive so tests can prove nodes actually get freed
+    #[derive(Debug)]
+    struct DropCounter {
+        live: Rc<std::cell::Cell<usize>>,
+    }
+
+    impl DropCounter {
+        fn new(live: &Rc<std::cell::Cell<usize>>) -> DropCounter {
+            live.set(live.get() + 1);
+            DropCounter { live: live.clone() }
+        }
+    }
+
+    impl Clone for DropCounter {
+        fn clone(&self) -> Self {
+            DropCounter::new(&self.live)
+        }
     }
 
-    pub fn iter(&self) -> ListIteratorTracker {
-        ListIteratorTracker::new(self.head.clone())
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.live.set(self.live.get() - 1);
+        }
     }
 
-    pub fn iter_rev(self) -> ListIteratorTracker {
-        ListIteratorTracker::new(self.tail)
+    #[test]
+    fn test_clearing_a_thousand_nodes() {
+        let live = Rc::new(std::cell::Cell::new(0));
+        let mut tl = BetterTransactionLog::new_empty();
+        for _ in 0..1000 {
+            tl.append(DropCounter::new(&live));
+        }
+        tl.clear();
+        assert!(tl.is_empty());
+        assert_eq!(tl.len(), 0);
+        assert_eq!(live.get(), 0); // nothing left alive by a stray link
+        tl.append(DropCounter::new(&live));
+        assert_eq!(tl.len(), 1);
+        assert_eq!(live.get(), 1);
     }
-}
 
-// This struct holds the state of the iterator
-pub struct ListIteratorTracker {
-    current: Link,
-}
+    #[test]
+    fn test_draining_frees_every_node() {
+        let live = Rc::new(std::cell::Cell::new(0));
+        let mut tl: BetterTransactionLog<DropCounter> =
+            (0..5).map(|_| DropCounter::new(&live)).collect();
+        let mut drain = tl.drain();
+        for remaining in (0..5).rev() {
+            drop(drain.next());
+            assert_eq!(drain.len(), remaining); // the log's length goes down with every entry handed out
+            assert_eq!(live.get(), remaining);
+        }
+        assert!(drain.next().is_none());
+        drop(drain);
+        assert!(tl.is_empty());
+        assert!(tl.head.is_none());
+        assert!(tl.tail.is_none());
 
-impl ListIteratorTracker {
-    fn new(start_at: Link) -> ListIteratorTracker {
-        ListIteratorTracker { current: start_at }
+        // Dropping it part way through still empties the log, same as Vec::drain
+        tl.extend((0..5).map(|_| DropCounter::new(&live)));
+        let mut drain = tl.drain();
+        drop(drain.next());
+        drop(drain);
+        assert_eq!(tl.len(), 0);
+        assert_eq!(live.get(), 0);
     }
-}
 
-impl Iterator for ListIteratorTracker {
-    type Item = String;
+    #[test]
+    fn test_dropping_frees_every_node() {
+        let live = Rc::new(std::cell::Cell::new(0));
+        let mut tl = BetterTransactionLog::new_empty();
+        for _ in 0..10 {
+            tl.append(DropCounter::new(&live));
+        }
+        assert_eq!(live.get(), 10);
+        drop(tl);
+        assert_eq!(live.get(), 0);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let current = &self.current;
-        let mut result = None;
-        self.current = match current {
-            Some(ref current) => {
-                let current = current.borrow();
-                result = Some(current.value.clone());
-                current.next.clone()
-            }
-            None => None,
-        };
-        // Huh. On Intellij Rust this highlights `result` with an E0308 error,
-        // but it does in fact compile and run. The same is not the case for VSCode
-        result
+        // The iterator owns the nodes now. With strong prev links the rest of the chain would keep itself alive when
+        // it's dropped, and without the log's own Drop behind it a million of them would overflow the stack
+        let mut tl = BetterTransactionLog::new_empty();
+        for _ in 0..1_000_000 {
+            tl.append(DropCounter::new(&live));
+        }
+        let mut iter = tl.into_iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 999_998);
+        drop(iter);
+        assert_eq!(live.get(), 0);
     }
-}
 
-impl DoubleEndedIterator for ListIteratorTracker {
-    fn next_back(&mut self) -> Option<String> {
-        let current = &self.current;
-        let mut result = None;
-        self.current = match current {
-            Some(ref curr) => {
-                let curr = curr.borrow();
-                result = Some(curr.value.clone());
-                curr.prev.clone()
-            }
-            None => None,
-        };
-        result
+    #[test]
+    fn test_inserting() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert_eq!(tl.insert(0, String::from("Testing2")), Ok(())); // into an empty log
+        assert_eq!(tl.insert(0, String::from("Testing1")), Ok(())); // front
+        assert_eq!(tl.insert(2, String::from("Testing4")), Ok(())); // tail, same as append
+        assert_eq!(tl.insert(2, String::from("Testing3")), Ok(())); // middle
+        assert_eq!(tl.len(), 4);
+        assert_eq!(tl.peek(), Some(String::from("Testing1")));
+        assert_eq!(tl.peek_back(), Some(String::from("Testing4")));
+        assert_eq!(
+            tl.iter().collect::<Vec<String>>(),
+            vec!["Testing1", "Testing2", "Testing3", "Testing4"]
+        );
+        assert_eq!(
+            tl.iter_rev().collect::<Vec<String>>(),
+            vec!["Testing4", "Testing3", "Testing2", "Testing1"]
+        );
+
+        assert_eq!(
+            tl.insert(5, String::from("Testing6")),
+            Err(OutOfBounds {
+                index: 5,
+                length: 4
+            })
+        );
+        assert_eq!(tl.len(), 4);
     }
-}
 
-impl IntoIterator for BetterTransactionLog {
-    type Item = String;
-    type IntoIter = ListIteratorTracker;
+    #[test]
+    fn test_removing_at() {
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.extend((1..=5).map(|i| format!("Testing{}", i)));
 
-    fn into_iter(self) -> Self::IntoIter {
-        ListIteratorTracker::new(self.head)
+        assert_eq!(tl.remove_at(5), None); // out of range
+        assert_eq!(tl.len(), 5);
+        assert_eq!(tl.remove_at(0), Some(String::from("Testing1"))); // head
+        assert_eq!(tl.len(), 4);
+        assert_eq!(tl.remove_at(1), Some(String::from("Testing3"))); // middle
+        assert_eq!(tl.len(), 3);
+        assert_eq!(tl.remove_at(2), Some(String::from("Testing5"))); // tail
+        assert_eq!(tl.len(), 2);
+        assert_eq!(
+            tl.iter().collect::<Vec<String>>(),
+            vec!["Testing2", "Testing4"]
+        );
+        assert_eq!(
+            tl.iter_rev().collect::<Vec<String>>(),
+            vec!["Testing4", "Testing2"]
+        );
+
+        assert_eq!(tl.remove_at(1), Some(String::from("Testing4")));
+        assert_eq!(tl.remove_at(0), Some(String::from("Testing2"))); // the only node
+        assert_eq!(tl.len(), 0);
+        assert!(tl.head.is_none());
+        assert!(tl.tail.is_none());
+        assert_eq!(tl.remove_at(0), None);
     }
-}
 
-// For production usage, a super deep linked list will cause stack overflow for the default recursive drop implementation
-// For production, probably safer to just use the some other implementation of LinkedList
-impl Drop for TransactionLog {
-    fn drop(&mut self) {
-        while self.pop().is_some() {}
+    #[test]
+    fn test_partial_drain_frees_every_node() {
+        let live = Rc::new(std::cell::Cell::new(0));
+        let mut tl = BetterTransactionLog::new_empty();
+        for _ in 0..10 {
+            tl.append(DropCounter::new(&live));
+        }
+        tl.drain().take(3).for_each(drop);
+        assert_eq!(live.get(), 0);
+        assert!(tl.is_empty());
     }
-}
 
-// Similarly here, the default derive(Debug) will cause Stack Overflow when printing out
-impl Debug for Node {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("NOD")
-            .field("irreplaceable", &self.value)
-            .field("back2back", &self.prev.is_some()) // WOW! representing node without causing StackOverflow is proving to be quite the thorn!
-            .field("forwards", &self.next.is_some())
-            .finish()
+    #[test]
+    fn test_truncating() {
+        let live = Rc::new(std::cell::Cell::new(0));
+        let mut tl = BetterTransactionLog::new_empty();
+        for _ in 0..100_000 {
+            tl.append(DropCounter::new(&live));
+        }
+        tl.truncate(10);
+        assert_eq!(tl.len(), 10);
+        assert_eq!(live.get(), 10); // the other 99,990 were actually freed
+        assert_eq!(tl.iter_rev().count(), 10); // the new tail is wired up
+        assert!(tl.tail.clone().unwrap().borrow().next.is_none());
+
+        tl.truncate(20); // longer than the log, so nothing happens
+        assert_eq!(tl.len(), 10);
+        tl.truncate(0); // same as clear
+        assert!(tl.is_empty());
+        assert_eq!(live.get(), 0);
     }
-}
 
-#[cfg(test)]
-mod better_transaction_log_tests {
-    use super::*;
+    #[test]
+    fn test_truncate_keeps_log_usable() {
+        let mut tl = BetterTransactionLog::from(vec!["a", "b", "c", "d", "e"]);
+        let discarded = tl.tail.clone().unwrap();
+        tl.truncate(5); // exactly the length is a no-op too
+        assert_eq!(tl.to_vec(), vec!["a", "b", "c", "d", "e"]);
+        tl.truncate(2);
+        assert_eq!(tl.to_vec(), vec!["a", "b"]);
+        assert_eq!(tl.iter_rev().collect::<Vec<_>>(), vec!["b", "a"]);
+        assert!(discarded.borrow().prev.is_none()); // cut loose from what came before
+
+        // Carries on like any other log afterwards
+        tl.append("f");
+        assert_eq!(tl.to_vec(), vec!["a", "b", "f"]);
+        assert_eq!(tl.pop_back(), Some("f"));
+        tl.truncate(0);
+        assert!(tl.is_empty());
+        assert_eq!(tl.iter().next(), None);
+        tl.append("g");
+        assert_eq!(tl.to_vec(), vec!["g"]);
+    }
 
     #[test]
-    fn test_appending() {
+    fn test_dropping_long_log() {
         let mut tl = BetterTransactionLog::new_empty();
-        assert!(tl.head.is_none());
-        assert!(tl.tail.is_none());
-        assert_eq!(tl.length, 0);
-        tl.append(String::from("Testing1"));
-        assert_eq!(tl.length, 1);
-        assert_eq!(tl.head, Some(Node::new("Testing1".to_string()))); // node without a next
-        assert_eq!(tl.tail, Some(Node::new("Testing1".to_string())));
-        tl.append(String::from("Testing2"));
-        assert_eq!(tl.length, 2);
-        assert!(tl.head.clone().unwrap().borrow().next.is_some()); // head has a next now
-        assert_eq!(
-            tl.tail.clone().unwrap().borrow().value,
-            String::from("Testing2")
-        );
-        tl.append(String::from("Testing3"));
-        assert_eq!(tl.length, 3);
-        assert!(tl
-            .head
-            .clone()
-            .unwrap()
-            .borrow()
-            .next
-            .clone()
-            .unwrap()
-            .borrow()
-            .next
-            .is_some());
-        assert_eq!(tl.tail.unwrap().borrow().value, String::from("Testing3"));
+        for i in 0..1_000_000 {
+            tl.append(i.to_string());
+        }
+        drop(tl); // the default recursive drop would overflow the stack here
     }
 
     #[test]
-    fn test_popping() {
+    fn test_dropping_clone_leaves_original() {
         let mut tl = BetterTransactionLog::new_empty();
         tl.append(String::from("Testing1"));
         tl.append(String::from("Testing2"));
-        tl.append(String::from("Testing3"));
-
-        assert_eq!(tl.pop(), Some("Testing1".to_string()));
-        assert_eq!(tl.length, 2);
-        assert!(tl.head.clone().unwrap().borrow().next.is_some());
+        drop(tl.clone());
+        assert_eq!(tl.len(), 2);
         assert_eq!(
-            tl.head
-                .clone()
-                .unwrap()
-                .borrow()
-                .next
-                .clone()
-                .unwrap()
-                .borrow()
-                .value,
-            String::from("Testing3") // Testing2 is the head now, and Testing3 is its next
+            tl.iter().collect::<Vec<String>>(),
+            vec!["Testing1", "Testing2"]
         );
-        assert_eq!(tl.pop(), Some(String::from("Testing2")));
-        assert_eq!(tl.length, 1);
-        assert_eq!(tl.pop(), Some(String::from("Testing3")));
-        assert_eq!(tl.length, 0);
-        assert_eq!(tl.head, None);
-        assert!(tl.tail.is_none());
     }
 
     #[test]
     fn test_next() {
-        let mut tracker = ListIteratorTracker::new(Some(Node::new(String::from("testing"))));
+        let node = Some(Node::new(String::from("testing")));
+        let mut tracker = ListIteratorTracker::new(node.clone(), node, 1);
         assert!(tracker.next().is_some());
+        assert!(tracker.next().is_none());
     }
 
     #[test]
     fn test_next_back() {
-        let mut tracker = ListIteratorTracker::new(Some(Node::new(String::from("testing"))));
+        let node = Some(Node::new(String::from("testing")));
+        let mut tracker = ListIteratorTracker::new(node.clone(), node, 1);
         assert!(tracker.next_back().is_some());
+        assert!(tracker.next_back().is_none());
+    }
+
+    #[test]
+    fn test_iter_does_not_consume() {
+        let mut tl = BetterTransactionLog::new_empty();
+        assert_eq!(tl.iter().next(), None);
+        tl.append(String::from("vibes"));
+        tl.append(String::from("only"));
+
+        let first = tl.iter();
+        let second = tl.iter();
+        assert_eq!(
+            first.collect::<Vec<String>>(),
+            second.collect::<Vec<String>>()
+        );
+        assert_eq!(tl.iter().collect::<Vec<String>>(), vec!["vibes", "only"]);
+        assert_eq!(tl.len(), 2);
+    }
+
+    #[test]
+    fn test_iter_by_reference_then_pop() {
+        let mut tl = BetterTransactionLog::new_empty();
+        tl.append(String::from("vibes"));
+        tl.append(String::from("only"));
+
+        let mut seen = Vec::new();
+        for x in &tl {
+            seen.push(x);
+        }
+        assert_eq!(seen, vec!["vibes", "only"]);
+        tl.append(String::from("still appendable"));
+
+        let iter = tl.iter();
+        drop(iter);
+        assert_eq!(tl.pop(), Some(String::from("vibes")));
+        assert_eq!(tl.pop(), Some(String::from("only")));
+        assert_eq!(tl.pop(), Some(String::from("still appendable")));
+        assert_eq!(tl.pop(), None);
+    }
+
+    #[test]
+    fn test_collect_and_extend() {
+        let values = vec![
+            String::from("Testing1"),
+            String::from("Testing2"),
+            String::from("Testing3"),
+        ];
+        let mut tl = values
+            .clone()
+            .into_iter()
+            .collect::<BetterTransactionLog<String>>();
+        assert_eq!(tl.len(), 3);
+        assert_eq!(tl.iter().collect::<Vec<String>>(), values);
+
+        tl.extend(vec![String::from("Testing4"), String::from("Testing5")]);
+        assert_eq!(tl.len(), 5);
+        assert_eq!(tl.peek_back(), Some(String::from("Testing5")));
+        assert_eq!(
+            tl.clone().iter_rev().collect::<Vec<String>>(), // prev links were wired up along the way
+            vec!["Testing5", "Testing4", "Testing3", "Testing2", "Testing1"]
+        );
+
+        let empty: BetterTransactionLog<&str> = Vec::new().into_iter().collect();
+        assert!(empty.head.is_none());
+        assert!(empty.tail.is_none());
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn test_extending_from_any_iterator() {
+        let mut tl = BetterTransactionLog::from(vec![String::from("a"), String::from("b")]);
+        tl.extend(vec![
+            String::from("c"),
+            String::from("d"),
+            String::from("e"),
+        ]);
+        assert_eq!(tl.len(), 5);
+        assert_eq!(tl.to_vec(), vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(tl.peek_back(), Some(String::from("e")));
+
+        // Anything that iterates will do: a range, an adapter chain, another log
+        tl.extend((0..2).map(|i| i.to_string()));
+        tl.extend("x y".split(' ').map(String::from));
+        tl.extend(BetterTransactionLog::from(vec![String::from("z")]));
+        assert_eq!(tl.len(), 10);
+        assert_eq!(
+            tl.iter_rev().take(5).collect::<Vec<_>>(),
+            vec!["z", "y", "x", "1", "0"]
+        );
+    }
+
+    #[test]
+    fn test_interleaving_next_and_next_back() {
+        let tl: BetterTransactionLog<String> = vec!["a", "b", "c", "d"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let mut iter = tl.iter();
+        assert_eq!(iter.next(), Some(String::from("a")));
+        assert_eq!(iter.next(), Some(String::from("b")));
+        assert_eq!(iter.next_back(), Some(String::from("d")));
+        assert_eq!(iter.next(), Some(String::from("c"))); // front cursor meets the back one here
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(
+            tl.iter().rev().collect::<Vec<String>>(),
+            vec!["d", "c", "b", "a"]
+        );
+    }
+
+    #[test]
+    fn test_into_iter_from_both_ends() {
+        let tl = BetterTransactionLog::from(vec![1, 2, 3, 4, 5]);
+        let mut iter = tl.into_iter();
+        let yielded = vec![
+            iter.next_back(), // starts from the tail, not off the front
+            iter.next(),
+            iter.next_back(),
+            iter.next(),
+            iter.next_back(), // the cursors meet on the middle entry
+        ];
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(yielded, vec![Some(5), Some(1), Some(4), Some(2), Some(3)]); // every entry exactly once
+
+        let tl = BetterTransactionLog::from(vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            tl.into_iter().rev().collect::<Vec<_>>(),
+            vec![5, 4, 3, 2, 1]
+        );
     }
 
     #[test]
@@ -296,55 +4685,168 @@ mod better_transaction_log_tests {
             // This one also calls .next() over and over
             println!("Forwards: {:#}", x);
         }
-        for x in tl.clone().iter_rev().rev() {
-            // Hitting it with the uno reverse calls .next_back() over and over.
+        for x in tl.clone().iter_rev() {
+            // iter_rev already hit it with the uno reverse, so this calls .next_back() over and over.
             println!("Backwards: {:#}", x);
         }
         for x in tl.clone().iter().rev() {
             // Hitting it with the uno reverse calls .next_back() over and over.
             println!("Backwards: {:#}", x);
-            // But it only prints once because `iter()` sets the starter to Head. And head always returns None for next_back().
+            // This used to print only once, back when `iter()` had a single cursor starting at the head.
         }
 
-        // For this implementation, iter does not consume the elements so it's probably more like a seek until we set state to None
+        // With a cursor at each end, going back and forth eats the list from both sides instead of seeking
         let mut iter = tl.iter();
         println!("Can this be used back and forth?");
-        println!("{:?}", iter.next()); // Print vibes, set state to only
-        println!("{:?}", iter.next_back()); // Print only, set state to vibes
-        println!("{:?}", iter.next()); // Print vibes, set state to only
-        println!("{:?}", iter.next_back()); // Print only, set state to vibes
-        println!("{:?}", iter.next()); // Print vibes, set state to only
-        println!("{:?}", iter.next()); // Print only, set state to None
-        println!("{:?}", iter.next_back()); // All done! We've now fully consumed the iterator because the state is set to None so there is no way home
+        println!("{:?}", iter.next()); // Print vibes, front cursor moves to only
+        println!("{:?}", iter.next_back()); // Print only, the cursors have met
+        println!("{:?}", iter.next()); // None, everything has been handed out
+        println!("{:?}", iter.next_back()); // None
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn test_serializes_as_flat_list() {
+        let log = BetterTransactionLog::from(vec![1, 2, 3]);
+        assert_eq!(serde_json::to_string(&log).unwrap(), "[1,2,3]");
+    }
+
+    #[test]
+    fn test_round_trip_unicode() {
+        let log = BetterTransactionLog::from(vec![
+            "café".to_string(),
+            "日本語".to_string(),
+            "🦀 crab".to_string(),
+        ]);
+        let json = serde_json::to_string(&log).unwrap();
+        let restored: BetterTransactionLog<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.len(), 3);
+        assert_eq!(restored.to_vec(), log.to_vec());
+        // The prev links have to be there too, not just next
+        assert_eq!(
+            restored.iter_rev().collect::<Vec<_>>(),
+            vec!["🦀 crab", "日本語", "café"]
+        );
+        assert_eq!(restored.peek_back(), Some("🦀 crab".to_string()));
+    }
+
+    #[test]
+    fn test_round_trip_through_a_string() {
+        let log: BetterTransactionLog<String> = ["deposit 10", "withdraw 4", "deposit 2"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let json: String = serde_json::to_string(&log).unwrap();
+        assert_eq!(json, r#"["deposit 10","withdraw 4","deposit 2"]"#); // just the values, no node graph
+        let restored: BetterTransactionLog<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, log);
+    }
+
+    #[test]
+    fn test_empty_list_deserializes_to_empty_log() {
+        let restored: BetterTransactionLog<String> = serde_json::from_str("[]").unwrap();
+        assert!(restored.is_empty());
+        assert_eq!(restored.peek(), None);
+        assert_eq!(restored.peek_back(), None);
+
+        let empty = BetterTransactionLog::<u32>::new_empty();
+        assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+    }
+
+    #[test]
+    fn test_round_trip_deep_log() {
+        let log: BetterTransactionLog<u32> = (0..50_000).collect();
+        let json = serde_json::to_string(&log).unwrap();
+        let mut restored: BetterTransactionLog<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.len(), 50_000);
+        assert_eq!(restored.get(25_000), Some(25_000));
+        assert_eq!(restored.pop_back(), Some(49_999));
+        assert_eq!(restored.pop(), Some(0));
+        assert_eq!(restored.len(), 49_998);
+    }
+
+    #[test]
+    fn test_rejects_non_sequence() {
+        assert!(serde_json::from_str::<BetterTransactionLog<u32>>("{\"a\": 1}").is_err());
     }
 }
 
 #[cfg(test)]
 mod transaction_log_tests {
+    use super::better_transaction_log_tests::assert_lone_node;
     use super::*;
 
+    #[test]
+    fn test_default_and_last() {
+        let mut tl: TransactionLog<u8> = TransactionLog::default();
+        assert!(tl.is_empty());
+        assert_eq!(tl.last(), None);
+        tl.append(1);
+        tl.append(2);
+        assert_eq!(tl.last(), Some(2));
+        tl.pop();
+        tl.pop();
+        assert_eq!(tl.last(), None);
+    }
+
+    #[test]
+    fn test_equality() {
+        let mut a = TransactionLog::new_empty();
+        let mut b = TransactionLog::new_empty();
+        assert_eq!(a, b);
+        for _ in 0..100_000 {
+            a.append("same");
+            b.append("same");
+        }
+        assert_eq!(a, b); // separate nodes, same values, and no stack overflow
+
+        b.pop();
+        assert_ne!(a, b);
+        b.append("different");
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_non_string_values() {
+        let mut tl = TransactionLog::<u64>::new_empty();
+        tl.append(1);
+        tl.append(2);
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.pop(), Some(1));
+        assert_eq!(tl.pop(), Some(2));
+        assert_eq!(tl.pop(), None);
+
+        let mut btl = BetterTransactionLog::<u64>::new_empty();
+        btl.append(1);
+        btl.push_front(0);
+        btl.append(2);
+        assert_eq!(btl.iter().collect::<Vec<u64>>(), vec![0, 1, 2]);
+        assert_eq!(btl.pop_back(), Some(2));
+    }
+
     #[test]
     fn test_appending() {
         let mut tl = TransactionLog::new_empty();
         assert!(tl.head.is_none());
         assert!(tl.tail.is_none());
-        assert_eq!(tl.length, 0);
+        assert_eq!(tl.len(), 0);
         tl.append(String::from("Testing1"));
-        assert_eq!(tl.length, 1);
-        assert_eq!(tl.head, Some(Node::new("Testing1".to_string()))); // node without a next
-        assert_eq!(tl.tail, Some(Node::new("Testing1".to_string())));
+        assert_eq!(tl.len(), 1);
+        assert_lone_node(&tl.head, "Testing1"); // node without a next
+        assert_lone_node(&tl.tail, "Testing1");
         tl.append(String::from("Testing2"));
-        assert_eq!(tl.length, 2);
+        assert_eq!(tl.len(), 2);
         assert!(tl.head.clone().unwrap().borrow().next.is_some()); // head has a next now
-        assert_eq!(
-            tl.head.clone().unwrap().borrow().next,
-            Some(Node::new(String::from("Testing2"))) // does not have a next
-        );
-        assert_eq!(tl.tail, Some(Node::new(String::from("Testing2"))));
+        assert_lone_node(&tl.head.clone().unwrap().borrow().next, "Testing2"); // does not have a next
+        assert_lone_node(&tl.tail, "Testing2");
         tl.append(String::from("Testing3"));
-        assert_eq!(tl.length, 3);
-        assert_eq!(
-            tl.head
+        assert_eq!(tl.len(), 3);
+        assert_lone_node(
+            &tl.head
                 .clone()
                 .unwrap()
                 .borrow()
@@ -353,9 +4855,116 @@ mod transaction_log_tests {
                 .unwrap()
                 .borrow()
                 .next,
-            Some(Node::new(String::from("Testing3"))) // head is unchanged, but the chain groweth
+            "Testing3", // head is unchanged, but the chain groweth
+        );
+        assert_lone_node(&tl.tail, "Testing3");
+    }
+
+    #[test]
+    fn test_peeking() {
+        let mut tl = TransactionLog::new_empty();
+        assert_eq!(tl.peek(), None);
+        assert_eq!(tl.peek_back_with(|s: &String| s.len()), None);
+        tl.append(String::from("Testing1"));
+        tl.append(String::from("Testing2"));
+        assert_eq!(tl.peek(), Some(String::from("Testing1")));
+        assert_eq!(tl.peek_back(), Some(String::from("Testing2")));
+        assert_eq!(tl.peek_with(|s| s.starts_with("Test")), Some(true));
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.pop(), Some(String::from("Testing1")));
+    }
+
+    #[test]
+    fn test_dropping_long_log() {
+        let mut tl = TransactionLog::new_empty();
+        for i in 0..1_000_000 {
+            tl.append(i.to_string());
+        }
+        drop(tl);
+    }
+
+    #[test]
+    fn test_len_and_is_empty() {
+        let mut tl = TransactionLog::new_empty();
+        assert!(tl.is_empty());
+        tl.append(String::from("Testing1"));
+        assert!(!tl.is_empty());
+        assert_eq!(tl.len(), 1);
+        tl.pop();
+        assert!(tl.is_empty());
+        assert_eq!(tl.len(), 0);
+    }
+
+    #[test]
+    fn test_clearing() {
+        let mut tl = TransactionLog::new_empty();
+        for i in 0..10 {
+            tl.append(i);
+        }
+        tl.clear();
+        assert!(tl.is_empty());
+        assert_eq!(tl.len(), 0);
+        tl.append(10);
+        assert_eq!(tl.peek(), Some(10));
+    }
+
+    #[test]
+    fn test_append_all() {
+        let mut batched = TransactionLog::new_empty();
+        let mut looped = TransactionLog::new_empty();
+        batched.append_all(Vec::new()); // nothing to add to an empty log
+        assert!(batched.is_empty());
+        batched.append_all((0..100_000).map(|i| i.to_string()));
+        for i in 0..100_000 {
+            looped.append(i.to_string());
+        }
+        assert_eq!(batched, looped);
+        assert_eq!(batched.len(), 100_000);
+        assert_eq!(batched.peek_back(), Some(String::from("99999")));
+
+        batched.append_all(vec![String::from("a"), String::from("b")]);
+        assert_eq!(batched.len(), 100_002);
+        assert_eq!(batched.peek_back(), Some(String::from("b")));
+        assert_eq!(batched.pop(), Some(String::from("0")));
+    }
+
+    #[test]
+    fn test_clearing_a_thousand_nodes() {
+        let mut tl = TransactionLog::new_empty();
+        for i in 0..1000 {
+            tl.append(i);
+        }
+        tl.clear();
+        assert!(tl.is_empty());
+        assert_eq!(tl.len(), 0);
+        assert_eq!(tl.peek_back(), None);
+        tl.append(1000);
+        assert_eq!(tl.len(), 1);
+        assert_eq!(tl.pop(), Some(1000));
+    }
+
+    #[test]
+    fn test_try_popping() {
+        let mut tl = TransactionLog::new_empty();
+        assert_eq!(tl.try_pop(), Err(EmptyLogError));
+        tl.append(1);
+        tl.append(2);
+        assert_eq!(tl.try_pop(), Ok(1));
+        assert_eq!(tl.try_pop(), Ok(2));
+        assert_eq!(tl.try_pop(), Err(EmptyLogError));
+        assert_eq!(tl.len(), 0);
+    }
+
+    #[test]
+    fn test_debug_output() {
+        let mut tl = TransactionLog::new_empty();
+        tl.append("a");
+        tl.append("b");
+        tl.append("c");
+        assert_eq!(
+            format!("{:?}", tl),
+            r#"TransactionLog { length: 3, entries: ["a", "b", "c"] }"#
         );
-        assert_eq!(tl.tail, Some(Node::new(String::from("Testing3"))));
     }
 
     #[test]
@@ -366,18 +4975,15 @@ mod transaction_log_tests {
         tl.append(String::from("Testing3"));
 
         assert_eq!(tl.pop(), Some("Testing1".to_string()));
-        assert_eq!(tl.length, 2);
+        assert_eq!(tl.len(), 2);
         assert!(tl.head.clone().unwrap().borrow().next.is_some());
-        assert_eq!(
-            tl.head.clone().unwrap().borrow().next,
-            Some(Node::new(String::from("Testing3"))) // Testing2 is the head now, and Testing3 is its next
-        );
-        assert_eq!(tl.tail, Some(Node::new(String::from("Testing3"))));
+        assert_lone_node(&tl.head.clone().unwrap().borrow().next, "Testing3"); // Testing2 is the head now, and Testing3 is its next
+        assert_lone_node(&tl.tail, "Testing3");
         assert_eq!(tl.pop(), Some(String::from("Testing2")));
-        assert_eq!(tl.length, 1);
+        assert_eq!(tl.len(), 1);
         assert_eq!(tl.pop(), Some(String::from("Testing3")));
-        assert_eq!(tl.length, 0);
-        assert_eq!(tl.head, None);
+        assert_eq!(tl.len(), 0);
+        assert!(tl.head.is_none());
         assert!(tl.tail.is_none());
     }
 }
@@ -1,5 +1,3 @@
-mod lists;
-
 fn main() {
     println!("Hello, world!");
 }
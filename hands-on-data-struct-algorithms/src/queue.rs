@@ -0,0 +1,87 @@
+use crate::lists::TransactionLog;
+
+// TransactionLog already appends at the tail and pops from the head in O(1), which is exactly a FIFO queue.
+// This just gives it queue-shaped names
+pub struct Queue<T> {
+    log: TransactionLog<T>,
+}
+
+impl<T> Queue<T> {
+    pub fn new() -> Queue<T> {
+        Queue {
+            log: TransactionLog::new_empty(),
+        }
+    }
+
+    pub fn enqueue(&mut self, value: T) {
+        self.log.append(value);
+    }
+
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.log.pop()
+    }
+
+    pub fn len(&self) -> usize {
+        self.log.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.log.is_empty()
+    }
+}
+
+impl<T: Clone> Queue<T> {
+    // The nodes live behind Rc<RefCell>, so we can't lend out a reference and hand back a copy instead
+    pub fn front(&self) -> Option<T> {
+        self.log.peek()
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Queue::new()
+    }
+}
+
+#[cfg(test)]
+mod queue_tests {
+    use super::*;
+
+    #[test]
+    fn test_fifo_order() {
+        let mut queue = Queue::new();
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert_eq!(queue.front(), Some(1));
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+    }
+
+    #[test]
+    fn test_empty_queue() {
+        let mut queue: Queue<String> = Queue::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.front(), None);
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn test_interleaved() {
+        let mut queue = Queue::new();
+        queue.enqueue("a");
+        queue.enqueue("b");
+        assert_eq!(queue.dequeue(), Some("a"));
+        queue.enqueue("c");
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.dequeue(), Some("b"));
+        assert_eq!(queue.dequeue(), Some("c"));
+        assert!(queue.is_empty());
+        queue.enqueue("d"); // the tail was reset when the queue emptied out
+        assert_eq!(queue.front(), Some("d"));
+        assert_eq!(queue.dequeue(), Some("d"));
+        assert_eq!(queue.dequeue(), None);
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::LinkedList;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+
+// The logs in lists.rs are built out of Rc<RefCell>, which can't leave the thread that made them.
+// This is the same idea for sharing across threads: one Mutex around the whole list, so every operation
+// takes the lock once instead of locking node by node. std's LinkedList does the linking since the point here is the sharing.
+// Cloning hands out another handle to the same log, which is how it gets to the worker threads
+pub struct SharedTransactionLog<T> {
+    inner: Arc<Mutex<LinkedList<T>>>,
+}
+
+impl<T> SharedTransactionLog<T> {
+    pub fn new_empty() -> SharedTransactionLog<T> {
+        SharedTransactionLog {
+            inner: Arc::new(Mutex::new(LinkedList::new())),
+        }
+    }
+
+    // A thread that panicked while holding the lock can't have left the list half linked,
+    // every change is a single push or pop, so carry on with it rather than poisoning every other thread too
+    fn lock(&self) -> MutexGuard<'_, LinkedList<T>> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn append(&self, value: T) {
+        self.lock().push_back(value);
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        self.lock().pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+}
+
+impl<T: Clone> SharedTransactionLog<T> {
+    // Copies everything out while holding the lock, so other threads can keep appending while we look through it
+    pub fn iter_snapshot(&self) -> std::vec::IntoIter<T> {
+        let snapshot: Vec<T> = self.lock().iter().cloned().collect();
+        snapshot.into_iter()
+    }
+}
+
+impl<T: Send + 'static> SharedTransactionLog<T> {
+    // Producers send entries down the channel and get on with their day, only the background thread ever waits on the lock.
+    // The thread keeps going until every Sender (clones included) has been dropped, then it exits on its own.
+    // Joining the handle after that is the flush: once join returns, everything that was sent is in the log
+    pub fn spawn_appender(&self) -> (Sender<T>, JoinHandle<()>) {
+        let (sender, receiver) = mpsc::channel();
+        let log = self.clone();
+        let handle = thread::spawn(move || {
+            for value in receiver {
+                log.append(value);
+            }
+        });
+        (sender, handle)
+    }
+}
+
+impl<T> Clone for SharedTransactionLog<T> {
+    fn clone(&self) -> Self {
+        SharedTransactionLog {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Default for SharedTransactionLog<T> {
+    fn default() -> Self {
+        SharedTransactionLog::new_empty()
+    }
+}
+
+#[cfg(test)]
+mod shared_transaction_log_tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_is_send_and_sync() {
+        fn assert_send_sync<S: Send + Sync>() {}
+        assert_send_sync::<SharedTransactionLog<String>>();
+    }
+
+    #[test]
+    fn test_concurrent_appends() {
+        let log = SharedTransactionLog::new_empty();
+        let workers: Vec<_> = (0..8)
+            .map(|worker| {
+                let log = log.clone();
+                thread::spawn(move || {
+                    for i in 0..1000 {
+                        log.append(format!("worker {} entry {}", worker, i));
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        assert_eq!(log.len(), 8000); // no lost updates
+        let snapshot: Vec<String> = log.iter_snapshot().collect();
+        assert_eq!(snapshot.len(), 8000);
+        // Each worker's own entries still come out in the order it appended them
+        let worker_three: Vec<&String> = snapshot
+            .iter()
+            .filter(|entry| entry.starts_with("worker 3 "))
+            .collect();
+        assert_eq!(worker_three.len(), 1000);
+        for (i, entry) in worker_three.into_iter().enumerate() {
+            assert_eq!(*entry, format!("worker 3 entry {}", i));
+        }
+    }
+
+    #[test]
+    fn test_channel_fed_appender() {
+        let log = SharedTransactionLog::new_empty();
+        let (sender, appender) = log.spawn_appender();
+        let producers: Vec<_> = (0..4)
+            .map(|producer| {
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    for i in 0..500 {
+                        sender.send((producer, i)).unwrap();
+                    }
+                })
+            })
+            .collect();
+        drop(sender); // only the producers' clones are left now
+        for producer in producers {
+            producer.join().unwrap();
+        }
+        appender.join().unwrap(); // every sender is gone, so this returns once the channel is drained
+
+        assert_eq!(log.len(), 2000);
+        let snapshot: Vec<(i32, i32)> = log.iter_snapshot().collect();
+        for producer in 0..4 {
+            let sent: Vec<i32> = snapshot
+                .iter()
+                .filter(|(from, _)| *from == producer)
+                .map(|(_, i)| *i)
+                .collect();
+            assert_eq!(sent, (0..500).collect::<Vec<_>>()); // each producer's entries kept their order
+        }
+    }
+
+    #[test]
+    fn test_fifo_and_snapshot() {
+        let log = SharedTransactionLog::new_empty();
+        assert!(log.is_empty());
+        assert_eq!(log.pop(), None);
+        log.append(1);
+        log.append(2);
+        log.append(3);
+        let snapshot = log.iter_snapshot();
+        assert_eq!(log.pop(), Some(1));
+        assert_eq!(snapshot.collect::<Vec<_>>(), vec![1, 2, 3]); // taken before the pop
+        assert_eq!(log.len(), 2);
+    }
+}
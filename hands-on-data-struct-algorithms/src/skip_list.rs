@@ -0,0 +1,218 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// A sorted linked list with express lanes. Every node is on level 0, about half of them are also on level 1,
+// a quarter on level 2 and so on, so a search can skip over most of the list and drop down a level when it overshoots.
+// That gives expected O(log n) search and insert without any rebalancing.
+// Nodes live in a Vec and point at each other by index, which sidesteps the Rc<RefCell> dance of the transaction logs.
+// Index 0 is the head: it has no value and a forward link on every level
+
+const DEFAULT_MAX_LEVEL: usize = 16;
+
+struct SkipNode<T> {
+    value: Option<T>, // only the head has None
+    forward: Vec<Option<usize>>,
+}
+
+pub struct SkipList<T: Ord> {
+    nodes: Vec<SkipNode<T>>,
+    level: usize, // how many levels are actually in use right now
+    max_level: usize,
+    rng: XorShift,
+    length: usize,
+}
+
+impl<T: Ord> SkipList<T> {
+    pub fn new() -> SkipList<T> {
+        SkipList::with_max_level(DEFAULT_MAX_LEVEL)
+    }
+
+    // 16 levels comfortably covers tens of thousands of entries, go higher for bigger lists.
+    // Every list needs at least level 0, so 0 panics
+    pub fn with_max_level(max_level: usize) -> SkipList<T> {
+        if max_level == 0 {
+            panic!("a skip list needs a max level of at least 1");
+        }
+        SkipList {
+            nodes: vec![SkipNode {
+                value: None,
+                forward: vec![None; max_level],
+            }],
+            level: 1,
+            max_level,
+            rng: XorShift::from_clock(),
+            length: 0,
+        }
+    }
+
+    fn value_at(&self, index: usize) -> &T {
+        self.nodes[index]
+            .value
+            .as_ref()
+            .expect("only the head has no value, and nothing links to the head")
+    }
+
+    // For each level, the last node whose value is smaller than `value` (the head if there's none).
+    // Those are the nodes a new entry would get spliced in after
+    fn predecessors(&self, value: &T) -> Vec<usize> {
+        let mut update = vec![0; self.max_level];
+        let mut current = 0;
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.nodes[current].forward[level] {
+                if self.value_at(next) >= value {
+                    break;
+                }
+                current = next;
+            }
+            update[level] = current;
+        }
+        update
+    }
+
+    // Flip a coin for each extra level, so each level has roughly half the nodes of the one below
+    fn random_level(&mut self) -> usize {
+        let mut level = 1;
+        while level < self.max_level && self.rng.next() & 1 == 1 {
+            level += 1;
+        }
+        level
+    }
+
+    // Returns false (and drops the value) if it's already in the list
+    pub fn insert(&mut self, value: T) -> bool {
+        let update = self.predecessors(&value);
+        if let Some(next) = self.nodes[update[0]].forward[0] {
+            if *self.value_at(next) == value {
+                return false;
+            }
+        }
+        let level = self.random_level();
+        self.level = self.level.max(level);
+        let index = self.nodes.len();
+        let mut forward = Vec::with_capacity(level);
+        for (lane, &before) in update.iter().enumerate().take(level) {
+            forward.push(self.nodes[before].forward[lane].replace(index));
+        }
+        self.nodes.push(SkipNode {
+            value: Some(value),
+            forward,
+        });
+        self.length += 1;
+        true
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let update = self.predecessors(value);
+        match self.nodes[update[0]].forward[0] {
+            Some(next) => self.value_at(next) == value,
+            None => false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    // Level 0 has every node in order, so that's all iteration needs
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            next: self.nodes[0].forward[0],
+        }
+    }
+}
+
+impl<T: Ord> Default for SkipList<T> {
+    fn default() -> Self {
+        SkipList::new()
+    }
+}
+
+pub struct Iter<'a, T: Ord> {
+    list: &'a SkipList<T>,
+    next: Option<usize>,
+}
+
+impl<'a, T: Ord> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let current = self.next?;
+        self.next = self.list.nodes[current].forward[0];
+        Some(self.list.value_at(current))
+    }
+}
+
+// xorshift64, plenty random for coin flips and no need to pull in a crate for it
+struct XorShift(u64);
+
+impl XorShift {
+    fn from_clock() -> XorShift {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.subsec_nanos() as u64)
+            .unwrap_or(0);
+        XorShift(nanos | 1) // the state must never be all zeroes or it stays that way
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod skip_list_tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_matches_btreeset() {
+        let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+        let mut list = SkipList::new();
+        let mut reference = BTreeSet::new();
+        for _ in 0..1000 {
+            let key = rng.next() % 5000;
+            assert_eq!(list.insert(key), reference.insert(key));
+        }
+        assert_eq!(list.len(), reference.len());
+        for key in 0..5000 {
+            assert_eq!(list.contains(&key), reference.contains(&key));
+        }
+        assert!(list.iter().eq(reference.iter()));
+        assert!(list.iter().zip(list.iter().skip(1)).all(|(a, b)| a < b));
+    }
+
+    #[test]
+    fn test_small_max_level() {
+        let mut list = SkipList::with_max_level(1); // just a sorted linked list
+        for word in ["pear", "apple", "fig", "apple"] {
+            list.insert(word);
+        }
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec!["apple", "fig", "pear"]
+        );
+        assert!(list.contains(&"fig"));
+        assert!(!list.contains(&"kiwi"));
+    }
+
+    #[test]
+    fn test_empty() {
+        let list: SkipList<u8> = SkipList::default();
+        assert!(list.is_empty());
+        assert!(!list.contains(&0));
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "max level of at least 1")]
+    fn test_zero_max_level() {
+        SkipList::<u8>::with_max_level(0);
+    }
+}
@@ -0,0 +1,116 @@
+// Only one end of a stack ever gets touched, so a singly linked list with a plain Box per node is enough.
+// No Rc<RefCell> here means peek can hand out a real reference
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+pub struct Stack<T> {
+    head: Link<T>,
+    length: usize,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Stack<T> {
+        Stack {
+            head: None,
+            length: 0,
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        let node = Box::new(Node {
+            value,
+            next: self.head.take(),
+        });
+        self.head = Some(node);
+        self.length += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.head.take().map(|head| {
+            self.head = head.next;
+            self.length -= 1;
+            head.value
+        })
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|head| &head.value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Stack::new()
+    }
+}
+
+// Same deal as the transaction logs: the default drop recurses once per node
+impl<T> Drop for Stack<T> {
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+        }
+    }
+}
+
+#[cfg(test)]
+mod stack_tests {
+    use super::*;
+
+    #[test]
+    fn test_lifo_order() {
+        let mut stack = Stack::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        stack.push(4);
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(1));
+    }
+
+    #[test]
+    fn test_empty_stack() {
+        let mut stack: Stack<String> = Stack::new();
+        assert!(stack.is_empty());
+        assert_eq!(stack.peek(), None);
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.len(), 0);
+    }
+
+    #[test]
+    fn test_len() {
+        let mut stack = Stack::new();
+        for i in 0..10 {
+            stack.push(i);
+            assert_eq!(stack.len(), i + 1);
+        }
+        while stack.pop().is_some() {}
+        assert_eq!(stack.len(), 0);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_dropping_deep_stack() {
+        let mut stack = Stack::new();
+        for i in 0..1_000_000 {
+            stack.push(i);
+        }
+        drop(stack);
+    }
+}
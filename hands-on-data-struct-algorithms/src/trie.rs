@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+
+// Every node stands for the prefix spelled out on the way down to it, and `is_key` marks the ones that were
+// inserted as whole keys. The children are a BTreeMap so walking them goes in char order, which makes
+// keys_with_prefix come out sorted for free
+#[derive(Default)]
+struct TrieNode {
+    children: BTreeMap<char, TrieNode>,
+    is_key: bool,
+}
+
+#[derive(Default)]
+pub struct Trie {
+    root: TrieNode,
+    length: usize,
+}
+
+impl Trie {
+    pub fn new() -> Trie {
+        Trie::default()
+    }
+
+    // Says whether the key was new, like BinarySearchTree::insert
+    pub fn insert(&mut self, key: &str) -> bool {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        if node.is_key {
+            return false;
+        }
+        node.is_key = true;
+        self.length += 1;
+        true
+    }
+
+    // Only whole keys count here, "ca" isn't in there just because "cat" is
+    pub fn contains(&self, key: &str) -> bool {
+        self.find(key).is_some_and(|node| node.is_key)
+    }
+
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    // Every key under the prefix (the prefix itself too if it's a key), in sorted order
+    pub fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut keys = Vec::new();
+        let Some(start) = self.find(prefix) else {
+            return keys;
+        };
+        // Depth first with our own stack. Children go on in reverse so the smallest char comes off first
+        let mut stack = vec![(String::from(prefix), start)];
+        while let Some((key, node)) = stack.pop() {
+            if node.is_key {
+                keys.push(key.clone());
+            }
+            for (c, child) in node.children.iter().rev() {
+                let mut child_key = key.clone();
+                child_key.push(*c);
+                stack.push((child_key, child));
+            }
+        }
+        keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    // The node at the end of the path spelled by `prefix`, if there is one
+    fn find(&self, prefix: &str) -> Option<&TrieNode> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod trie_tests {
+    use super::*;
+
+    fn sample() -> Trie {
+        let mut trie = Trie::new();
+        for key in ["car", "cart", "cat", "dog", "do"] {
+            assert!(trie.insert(key));
+        }
+        trie
+    }
+
+    #[test]
+    fn test_prefix_matching() {
+        let trie = sample();
+        assert!(trie.starts_with("ca"));
+        assert!(trie.starts_with("cart"));
+        assert!(trie.starts_with("d"));
+        assert!(trie.starts_with("")); // everything starts with nothing
+        assert!(!trie.starts_with("cb"));
+        assert!(!trie.starts_with("carts"));
+    }
+
+    #[test]
+    fn test_exact_match_vs_prefix() {
+        let mut trie = sample();
+        assert!(trie.contains("car"));
+        assert!(trie.contains("do"));
+        assert!(!trie.contains("ca")); // only a prefix
+        assert!(!trie.contains(""));
+        assert!(!trie.contains("carts"));
+        assert!(!trie.insert("car")); // already there
+        assert_eq!(trie.len(), 5);
+        assert!(trie.insert("ca"));
+        assert!(trie.contains("ca"));
+        assert_eq!(trie.len(), 6);
+    }
+
+    #[test]
+    fn test_keys_with_prefix() {
+        let trie = sample();
+        assert_eq!(trie.keys_with_prefix("ca"), vec!["car", "cart", "cat"]);
+        assert_eq!(trie.keys_with_prefix("car"), vec!["car", "cart"]);
+        assert_eq!(trie.keys_with_prefix("do"), vec!["do", "dog"]);
+        assert_eq!(
+            trie.keys_with_prefix(""),
+            vec!["car", "cart", "cat", "do", "dog"]
+        );
+        assert!(trie.keys_with_prefix("x").is_empty());
+    }
+
+    #[test]
+    fn test_empty_trie() {
+        let trie = Trie::new();
+        assert!(trie.is_empty());
+        assert!(!trie.contains("a"));
+        assert!(trie.starts_with(""));
+        assert!(trie.keys_with_prefix("").is_empty());
+    }
+
+    #[test]
+    fn test_multibyte_keys() {
+        let mut trie = Trie::new();
+        trie.insert("über");
+        trie.insert("übel");
+        assert_eq!(trie.keys_with_prefix("üb"), vec!["übel", "über"]);
+        assert!(!trie.starts_with("u"));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::lists::{BetterTransactionLog, NodeHandle};
+
+// Undo/redo on top of the transaction log, the way an editor does it.
+// Everything ever appended sits in the log, and `applied` counts how many of those (from the front) are currently in effect.
+// Undoing steps that boundary back, redoing steps it forward, and appending after an undo throws away
+// whatever was waiting to be redone, since that branch of history can't come back anymore.
+// The boundary itself is kept as a handle to the last applied entry, so undo and redo are a single hop along the
+// prev or next link and walking the whole history either way is O(n). Only the append that cuts off a redo branch
+// walks, and truncate comes in from the tail, so that's O(redo depth)
+pub struct UndoLog<T> {
+    log: BetterTransactionLog<T>,
+    applied: usize,
+    last_applied: Option<NodeHandle<T>>, // None when everything has been undone
+}
+
+impl<T> UndoLog<T> {
+    pub fn new() -> UndoLog<T> {
+        UndoLog {
+            log: BetterTransactionLog::new_empty(),
+            applied: 0,
+            last_applied: None,
+        }
+    }
+
+    pub fn append(&mut self, value: T) {
+        self.log.truncate(self.applied);
+        self.last_applied = Some(self.log.append_with_handle(value));
+        self.applied += 1;
+    }
+
+    // How many entries can be undone
+    pub fn undo_depth(&self) -> usize {
+        self.applied
+    }
+
+    // How many undone entries are waiting to be redone
+    pub fn redo_depth(&self) -> usize {
+        self.log.len() - self.applied
+    }
+}
+
+impl<T: Clone> UndoLog<T> {
+    // Hands back the entry being undone. It stays in the log until an append cuts it off, so redo can bring it back
+    pub fn undo(&mut self) -> Option<T> {
+        let undone = self.last_applied.take()?;
+        self.last_applied = undone.prev();
+        self.applied -= 1;
+        undone.value()
+    }
+
+    // With nothing applied the next entry to redo is the very first one
+    pub fn redo(&mut self) -> Option<T> {
+        let redone = match &self.last_applied {
+            Some(last) => last.next(),
+            None => self.log.front_handle(),
+        }?;
+        let value = redone.value()?;
+        self.last_applied = Some(redone);
+        self.applied += 1;
+        Some(value)
+    }
+
+    // Just the entries currently in effect, oldest first
+    pub fn applied(&self) -> Vec<T> {
+        self.log.iter().take(self.applied).collect()
+    }
+}
+
+impl<T> Default for UndoLog<T> {
+    fn default() -> Self {
+        UndoLog::new()
+    }
+}
+
+#[cfg(test)]
+mod undo_log_tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_undo_and_redo() {
+        let mut log = UndoLog::new();
+        assert_eq!(log.undo(), None);
+        assert_eq!(log.redo(), None);
+
+        log.append("a");
+        log.append("b");
+        log.append("c");
+        assert_eq!(log.undo_depth(), 3);
+        assert_eq!(log.redo_depth(), 0);
+
+        assert_eq!(log.undo(), Some("c"));
+        assert_eq!(log.undo(), Some("b"));
+        assert_eq!(log.applied(), vec!["a"]);
+        assert_eq!(log.undo_depth(), 1);
+        assert_eq!(log.redo_depth(), 2);
+
+        assert_eq!(log.redo(), Some("b"));
+        assert_eq!(log.applied(), vec!["a", "b"]);
+        assert_eq!(log.redo_depth(), 1);
+
+        log.append("d"); // "c" is gone for good now
+        assert_eq!(log.redo_depth(), 0);
+        assert_eq!(log.redo(), None);
+        assert_eq!(log.applied(), vec!["a", "b", "d"]);
+
+        assert_eq!(log.undo(), Some("d"));
+        assert_eq!(log.undo(), Some("b"));
+        assert_eq!(log.undo(), Some("a"));
+        assert_eq!(log.undo(), None);
+        assert_eq!(log.redo_depth(), 3);
+    }
+
+    #[test]
+    fn test_walking_a_long_history() {
+        // Each step is one hop, so going all the way back and forth again doesn't take long
+        let mut log = UndoLog::new();
+        for i in 0..100_000 {
+            log.append(i);
+        }
+        for i in (0..100_000).rev() {
+            assert_eq!(log.undo(), Some(i));
+        }
+        assert_eq!(log.undo(), None);
+        for i in 0..100_000 {
+            assert_eq!(log.redo(), Some(i));
+        }
+        assert_eq!(log.redo(), None);
+
+        for _ in 0..10 {
+            log.undo();
+        }
+        log.append(-1);
+        assert_eq!(log.undo_depth(), 99_991);
+        assert_eq!(log.undo(), Some(-1));
+        assert_eq!(log.undo(), Some(99_989));
+        assert_eq!(log.redo(), Some(99_989));
+        assert_eq!(log.redo(), Some(-1));
+        assert_eq!(log.redo(), None);
+    }
+
+    #[test]
+    fn test_discarded_branch_is_freed() {
+        let doomed = Rc::new(String::from("doomed"));
+        let mut log = UndoLog::new();
+        log.append(Rc::new(String::from("kept")));
+        log.append(doomed.clone());
+        log.append(doomed.clone());
+        assert_eq!(Rc::strong_count(&doomed), 3);
+
+        drop(log.undo());
+        drop(log.undo());
+        assert_eq!(Rc::strong_count(&doomed), 3); // undone but still redoable
+
+        log.append(Rc::new(String::from("replacement")));
+        assert_eq!(Rc::strong_count(&doomed), 1); // only our own copy is left
+        assert_eq!(log.undo_depth(), 2);
+        assert_eq!(log.redo_depth(), 0);
+    }
+}